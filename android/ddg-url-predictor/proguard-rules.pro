@@ -18,4 +18,9 @@
 
 # If you keep the line number information, uncomment this to
 # hide the original source file name.
-#-renamesourcefileattribute SourceFile
\ No newline at end of file
+#-renamesourcefileattribute SourceFile
+
+# The native library constructs decisions through JNI
+-keep class com.duckduckgo.urlpredictor.Decision$* {
+    <init>(...);
+}
@@ -1,12 +1,34 @@
 package com.duckduckgo.urlpredictor
 
 import kotlinx.serialization.Serializable
+import kotlinx.serialization.Transient
 import kotlinx.serialization.json.Json
 import kotlinx.serialization.json.jsonObject
 
+enum class DecisionKind { Navigate, Search, Blocked }
+
+// Built by the native library (`ddgClassifyDecisionJni`): keep the constructors' signatures in sync with lib.rs
 sealed interface Decision {
-    @Serializable data class Navigate(val url: String) : Decision
-    @Serializable data class Search(val query: String) : Decision
+    val kind: DecisionKind
+
+    /** Reason code name (`"Calculation"`, `"Credentials"`, ...) when the input was recognized explicitly */
+    val reason: String? get() = null
+
+    @Serializable data class Navigate(
+        val url: String,
+        /** Warning names (`"PossibleSpoof"`, `"CredentialsStripped"`, ...) to surface before or while navigating */
+        @Transient val warnings: List<String> = emptyList(),
+    ) : Decision {
+        override val kind get() = DecisionKind.Navigate
+    }
+
+    @Serializable data class Search(val query: String, override val reason: String? = null) : Decision {
+        override val kind get() = DecisionKind.Search
+    }
+
+    @Serializable data class Blocked(val url: String, val threat: String, override val reason: String? = null) : Decision {
+        override val kind get() = DecisionKind.Blocked
+    }
 }
 
 object DecisionJson {
@@ -18,11 +40,12 @@ object DecisionJson {
 
     fun decodeDecision(jsonStr: String): Decision {
         val root = json.parseToJsonElement(jsonStr).jsonObject
-        // Expect exactly one entry: "Navigate" | "Search"
+        // Expect exactly one entry: "Navigate" | "Search" | "Blocked"
         val (tag, payloadEl) = root.entries.first()
         return when (tag) {
             "Navigate" -> json.decodeFromJsonElement(Decision.Navigate.serializer(), payloadEl)
             "Search"   -> json.decodeFromJsonElement(Decision.Search.serializer(), payloadEl)
+            "Blocked"  -> json.decodeFromJsonElement(Decision.Blocked.serializer(), payloadEl)
             else -> error("Unknown decision: $tag")
         }
     }
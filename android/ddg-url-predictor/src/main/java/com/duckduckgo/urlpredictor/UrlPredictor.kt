@@ -5,9 +5,9 @@ import android.os.Looper
 class UrlPredictor {
 
     companion object {
-        private val defaultPolicy = DecisionJson.Policy()
+        // the Android preset (`Policy::android()`), owned by the native library
         private val defaultPolicyJson: String by lazy {
-            DecisionJson.encodePolicy(defaultPolicy)
+            get().ddgDefaultPolicy()
         }
 
         @Volatile private var instance: UrlPredictor? = null
@@ -78,20 +78,26 @@ class UrlPredictor {
 
     // Low-level JNI (returns JSON from Rust)
     private external fun ddgClassifyJni(input: String, policyJson: String): String
+    // Returns the Decision built by Rust, with no JSON to parse
+    private external fun ddgClassifyDecisionJni(input: String, policyJson: String): Decision
+    private external fun ddgRegistrableDomain(host: String): String?
+    private external fun ddgDefaultPolicy(): String
 
     // High-level, type-safe API
     fun classify(input: String): Decision {
         return classifyInternal(input)
     }
 
-    private fun classifyInternal(input: String, policy: DecisionJson.Policy = defaultPolicy): Decision {
-        // for now we don't want to expose the default policy in the public API, so optimising a bit
-        val policyJson = if (policy === defaultPolicy) {
-            defaultPolicyJson
-        } else {
-            DecisionJson.encodePolicy(policy)
-        }
-        val decisionJson = ddgClassifyJni(input, policyJson)
-        return DecisionJson.decodeDecision(decisionJson)
+    /**
+     * Registrable domain (eTLD+1) of [host] per the Public Suffix List bundled
+     * with the native library, in ASCII and lowercase, e.g. `mail.google.com` →
+     * `google.com`. Returns null for public suffixes, IP addresses and invalid hosts.
+     */
+    fun registrableDomain(host: String): String? = ddgRegistrableDomain(host)
+
+    private fun classifyInternal(input: String, policy: DecisionJson.Policy? = null): Decision {
+        // for now we don't want to expose the default policy in the public API
+        val policyJson = policy?.let(DecisionJson::encodePolicy) ?: defaultPolicyJson
+        return ddgClassifyDecisionJni(input, policyJson)
     }
 }
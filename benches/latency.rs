@@ -0,0 +1,206 @@
+// benches/latency.rs
+//
+// Classification latency per input category, with a JSON baseline to compare
+// against before sending a heuristic change:
+//
+//   git stash && cargo bench --bench latency -- --save target/latency-baseline.json
+//   git stash pop && cargo bench --bench latency -- --compare target/latency-baseline.json
+//
+// `--compare` exits with 1 when a category's p50 or p90 grows by more than
+// `--threshold` percent (default 20). Baselines only compare on the machine
+// that recorded them, so they aren't checked in.
+
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+use url_predictor::{classify, Policy};
+
+/// Samples per category and round, spread over its inputs.
+const SAMPLES: usize = 4_000;
+
+/// Classifications timed together per sample, so sub-microsecond calls
+/// aren't lost in the clock's resolution and overhead.
+const BATCH: u32 = 8;
+
+/// Rounds per category; each percentile is the lowest across rounds, which
+/// is the least disturbed by whatever else the machine is doing.
+const ROUNDS: usize = 3;
+
+const DEFAULT_THRESHOLD_PERCENT: f64 = 20.0;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Percentiles {
+    p50_ns: u64,
+    p90_ns: u64,
+    p99_ns: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Baseline {
+    categories: BTreeMap<String, Percentiles>,
+}
+
+struct Category {
+    name: &'static str,
+    policy: Policy,
+    inputs: Vec<String>,
+}
+
+fn categories() -> Vec<Category> {
+    let long_url = |i: usize| {
+        let params: Vec<String> = (0..60).map(|n| format!("param{n}=value{}", n * i)).collect();
+        format!("https://www.example.com/{}/index.html?{}#section-{i}", "segment/".repeat(40), params.join("&"))
+    };
+    let file_paths = Policy { allow_file_paths: true, ..Policy::default() };
+    vec![
+        Category {
+            name: "plain_query",
+            policy: Policy::default(),
+            inputs: ["weather tomorrow", "how to boil an egg", "rust borrow checker explained", "best pizza near me", "lyrics bohemian rhapsody", "test"]
+                .map(String::from)
+                .to_vec(),
+        },
+        Category { name: "long_url", policy: Policy::default(), inputs: (0..8).map(long_url).collect() },
+        Category {
+            name: "ipv6",
+            policy: Policy::default(),
+            inputs: ["[::1]", "[::1]:8080", "http://[2001:db8::1]/a", "[fe80::1%25eth0]", "[::ffff:127.0.0.1]:443/path", "2001:db8::1"]
+                .map(String::from)
+                .to_vec(),
+        },
+        Category {
+            name: "file_path",
+            policy: file_paths,
+            inputs: ["C:\\Users\\me\\Documents\\report.pdf", "/etc/hosts", "~/Documents/notes.md", "\\\\server\\share\\file.txt", "file:///C:/Windows/win.ini", "c:/temp/a.txt"]
+                .map(String::from)
+                .to_vec(),
+        },
+    ]
+}
+
+fn measure(category: &Category) -> Percentiles {
+    // lazily built tables would otherwise count against the first samples
+    for input in &category.inputs {
+        black_box(classify(input, &category.policy));
+    }
+    let rounds: Vec<Percentiles> = (0..ROUNDS).map(|_| measure_round(category)).collect();
+    let best = |p: fn(&Percentiles) -> u64| rounds.iter().map(p).min().unwrap_or(0);
+    Percentiles { p50_ns: best(|p| p.p50_ns), p90_ns: best(|p| p.p90_ns), p99_ns: best(|p| p.p99_ns) }
+}
+
+fn measure_round(category: &Category) -> Percentiles {
+    let mut latencies: Vec<u64> = (0..SAMPLES)
+        .map(|i| {
+            let input = &category.inputs[i % category.inputs.len()];
+            let start = Instant::now();
+            for _ in 0..BATCH {
+                black_box(classify(black_box(input), &category.policy));
+            }
+            u64::try_from((start.elapsed() / BATCH).as_nanos()).unwrap_or(u64::MAX)
+        })
+        .collect();
+    latencies.sort_unstable();
+    let percentile = |p: usize| latencies[(latencies.len() * p / 100).min(latencies.len() - 1)];
+    Percentiles { p50_ns: percentile(50), p90_ns: percentile(90), p99_ns: percentile(99) }
+}
+
+enum Mode {
+    Report,
+    Save(String),
+    Compare { path: String, threshold_percent: f64 },
+}
+
+fn parse_args() -> Result<Mode, String> {
+    let (mut save, mut compare, mut threshold_percent) = (None, None, DEFAULT_THRESHOLD_PERCENT);
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            // passed by `cargo bench`
+            "--bench" => {}
+            "--save" => save = Some(args.next().ok_or("--save needs a file")?),
+            "--compare" => compare = Some(args.next().ok_or("--compare needs a file")?),
+            "--threshold" => {
+                let value = args.next().ok_or("--threshold needs a percentage")?;
+                threshold_percent = value.parse().map_err(|_| format!("bad --threshold {value}"))?;
+            }
+            _ => return Err(format!("unexpected argument {arg}")),
+        }
+    }
+    match (save, compare) {
+        (Some(_), Some(_)) => Err("--save and --compare are exclusive".into()),
+        (Some(path), None) => Ok(Mode::Save(path)),
+        (None, Some(path)) => Ok(Mode::Compare { path, threshold_percent }),
+        (None, None) => Ok(Mode::Report),
+    }
+}
+
+/// Percentiles of `current` more than `threshold_percent` above `baseline`.
+fn regressions(baseline: &Percentiles, current: &Percentiles, threshold_percent: f64) -> Vec<String> {
+    let limit = |ns: u64| ns as f64 * (1.0 + threshold_percent / 100.0);
+    [("p50", baseline.p50_ns, current.p50_ns), ("p90", baseline.p90_ns, current.p90_ns)]
+        .into_iter()
+        .filter(|(_, before, after)| *after as f64 > limit(*before))
+        .map(|(name, before, after)| format!("{name} {before}ns -> {after}ns"))
+        .collect()
+}
+
+fn main() -> ExitCode {
+    let mode = match parse_args() {
+        Ok(mode) => mode,
+        Err(e) => {
+            eprintln!("latency: {e}");
+            return ExitCode::from(2);
+        }
+    };
+    let baseline = match &mode {
+        Mode::Compare { path, .. } => {
+            match std::fs::read_to_string(path).map_err(|e| e.to_string()).and_then(|json| serde_json::from_str::<Baseline>(&json).map_err(|e| e.to_string())) {
+                Ok(baseline) => Some(baseline),
+                Err(e) => {
+                    eprintln!("latency: {path}: {e}");
+                    return ExitCode::from(2);
+                }
+            }
+        }
+        _ => None,
+    };
+
+    let mut current = Baseline::default();
+    let mut regressed = false;
+    println!("{:<12} {:>9} {:>9} {:>9}", "category", "p50 ns", "p90 ns", "p99 ns");
+    for category in categories() {
+        let percentiles = measure(&category);
+        let mut line = format!("{:<12} {:>9} {:>9} {:>9}", category.name, percentiles.p50_ns, percentiles.p90_ns, percentiles.p99_ns);
+        if let (Some(baseline), Mode::Compare { threshold_percent, .. }) = (&baseline, &mode) {
+            match baseline.categories.get(category.name) {
+                Some(before) => {
+                    let found = regressions(before, &percentiles, *threshold_percent);
+                    if !found.is_empty() {
+                        regressed = true;
+                        line.push_str(&format!("  REGRESSED: {}", found.join(", ")));
+                    }
+                }
+                None => line.push_str("  (not in baseline)"),
+            }
+        }
+        println!("{line}");
+        current.categories.insert(category.name.to_string(), percentiles);
+    }
+
+    if let Mode::Save(path) = &mode {
+        let json = serde_json::to_string_pretty(&current).expect("baseline serializes");
+        if let Err(e) = std::fs::write(path, json + "\n") {
+            eprintln!("latency: {path}: {e}");
+            return ExitCode::from(2);
+        }
+        println!("saved baseline to {path}");
+    }
+    if regressed {
+        eprintln!("latency regressed beyond the threshold");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
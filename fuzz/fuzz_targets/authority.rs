@@ -0,0 +1,20 @@
+//! The hand-rolled IP address and `localhost` authority splitter. Whatever it
+//! navigates to must parse, with an IP address or `localhost` as the host.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use url::{Host, Url};
+use url_predictor::fuzzing::ip_or_localhost_url;
+
+fuzz_target!(|input: &str| {
+    let Some(url) = ip_or_localhost_url(input) else {
+        return;
+    };
+    let parsed = Url::parse(&url).unwrap_or_else(|e| panic!("{input:?} navigates to unparsable {url:?}: {e}"));
+    match parsed.host() {
+        Some(Host::Ipv4(_) | Host::Ipv6(_)) => {}
+        Some(Host::Domain(domain)) => assert_eq!(domain, "localhost", "{input:?} navigates to {url:?}"),
+        None => panic!("{input:?} navigates to {url:?} without a host"),
+    }
+});
@@ -0,0 +1,56 @@
+//! `classify_with_context` on arbitrary input under every preset, platform
+//! and a few locales. Navigated URLs must parse, and decisions must survive
+//! the v1 wire format and a serde round trip.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use url::Url;
+use url_predictor::{classify_with_context, decision_v1_json, ClassifyContext, Decision, Platform, PolicyPreset};
+
+const PRESETS: &[PolicyPreset] = &[
+    PolicyPreset::Default,
+    PolicyPreset::Windows,
+    PolicyPreset::MacOs,
+    PolicyPreset::Android,
+    PolicyPreset::Ios,
+    PolicyPreset::MacOsParity,
+    PolicyPreset::WindowsParity,
+    PolicyPreset::ChromiumParity,
+    PolicyPreset::SafariParity,
+];
+
+const PLATFORMS: &[Platform] = &[Platform::Windows, Platform::MacOs, Platform::Linux, Platform::Android, Platform::Ios];
+
+const LOCALES: &[&str] = &["en-US", "en-GB", "de-DE", "fr-FR", "pt-PT", "ja-JP"];
+
+#[derive(Debug, Arbitrary)]
+struct Input<'a> {
+    input: &'a str,
+    preset: u8,
+    platform: Option<u8>,
+    locale: Option<u8>,
+    allow_intranet_single_label: bool,
+    allow_file_paths: bool,
+}
+
+fuzz_target!(|data: Input| {
+    let mut policy = PRESETS[usize::from(data.preset) % PRESETS.len()].policy();
+    policy.allow_intranet_single_label |= data.allow_intranet_single_label;
+    policy.allow_file_paths |= data.allow_file_paths;
+    let ctx = ClassifyContext {
+        platform: data.platform.map(|i| PLATFORMS[usize::from(i) % PLATFORMS.len()]),
+        locale: data.locale.map(|i| LOCALES[usize::from(i) % LOCALES.len()].to_string()),
+        ..ClassifyContext::default()
+    };
+
+    let decision = classify_with_context(data.input, &policy, &ctx);
+    if let Decision::Navigate { url, .. } = &decision {
+        assert!(Url::parse(url).is_ok(), "{:?} navigates to unparsable {url:?}", data.input);
+    }
+    let v1: serde_json::Value = serde_json::from_str(&decision_v1_json(&decision, data.input)).unwrap();
+    assert!(v1.is_object());
+    let json = serde_json::to_string(&decision).unwrap();
+    assert_eq!(serde_json::from_str::<Decision>(&json).unwrap(), decision);
+});
@@ -0,0 +1,26 @@
+//! `ddg_up_classify_json` on arbitrary bytes: the input up to the first NUL
+//! and the policy JSON after it, neither necessarily UTF-8. The result must
+//! always be a JSON object.
+
+#![no_main]
+
+use std::ffi::{CStr, CString};
+
+use libfuzzer_sys::fuzz_target;
+use url_predictor::{ddg_up_classify_json, ddg_up_free_string};
+
+fuzz_target!(|data: &[u8]| {
+    let (input, policy) = match data.iter().position(|&b| b == 0) {
+        Some(nul) => (&data[..nul], &data[nul + 1..]),
+        None => (data, &b"{}"[..]),
+    };
+    let policy: Vec<u8> = policy.iter().copied().take_while(|&b| b != 0).collect();
+    let (input, policy) = (CString::new(input).unwrap(), CString::new(policy).unwrap());
+
+    let out = unsafe { ddg_up_classify_json(input.as_ptr(), policy.as_ptr()) };
+    assert!(!out.is_null());
+    let json = unsafe { CStr::from_ptr(out) }.to_str().expect("UTF-8 output").to_owned();
+    unsafe { ddg_up_free_string(out) };
+    let value: serde_json::Value = serde_json::from_str(&json).expect("JSON output");
+    assert!(value.is_object(), "{json}");
+});
@@ -5,7 +5,7 @@ set -euo pipefail
 LIB_NAME="${LIB_NAME:-url_predictor}"                          # Rust crate/library name (produces lib${LIB_NAME}.so)
 ANDROID_MODULE="${ANDROID_MODULE:-android/ddg-url-predictor}"  # Android library module path
 BUILD_TYPE="${BUILD_TYPE:-release}"                            # release|debug
-FEATURES="${FEATURES:-real-psl}"                               # cargo features (empty for none)
+FEATURES="${FEATURES:-real-psl,platform-android}"              # cargo features (empty for none)
 ABIS="${ABIS:-arm64-v8a armeabi-v7a x86_64 x86}"              # ABIs to build
 PUBLISH="${PUBLISH:-false}"                                    # Whether to publish to Maven (default: false)
 
@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+# Builds the browser playground bindings (feature `playground`): an ES module
+# exporting classify, explain and defaultPolicy, plus its .wasm. Needs the
+# wasm-bindgen CLI matching the crate's wasm-bindgen version.
+
+FEATURES="${FEATURES:-playground real-psl}"
+TARGET=wasm32-unknown-unknown
+DIST_DIR="dist/playground"
+
+rustup target add "${TARGET}"
+
+cargo build --release \
+  --features "${FEATURES}" \
+  --target "${TARGET}"
+
+wasm-bindgen --target web --out-dir "${DIST_DIR}" \
+  "target/${TARGET}/release/url_predictor.wasm"
@@ -0,0 +1,15 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+FEATURES="${FEATURES:-real-psl}"
+TARGET=wasm32-wasip1
+DIST_DIR="dist/wasi"
+
+rustup target add "${TARGET}"
+
+cargo build --release \
+  --features "${FEATURES}" \
+  --target "${TARGET}"
+
+mkdir -p "${DIST_DIR}"
+cp "target/${TARGET}/release/url_predictor.wasm" "${DIST_DIR}/"
@@ -2,7 +2,7 @@ $ErrorActionPreference = "Stop"
 
 $crate = "url_predictor"
 $features = ${env:FEATURES}
-if (-not $features) { $features = "real-psl" }
+if (-not $features) { $features = "real-psl,platform-windows" }
 
 # Define targets
 $targets = @(
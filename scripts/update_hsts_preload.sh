@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+HSTS_URL="https://raw.githubusercontent.com/chromium/chromium/main/net/http/transport_security_state_static.json"
+TARGET="assets/hsts_preload.txt"
+
+echo "Downloading Chromium HSTS preload list..."
+curl -sSf "$HSTS_URL" \
+  | grep -v '^ *//' \
+  | python3 -c '
+import json, sys
+entries = json.load(sys.stdin)["entries"]
+print("# HSTS preload hosts (from Chromium'"'"'s transport_security_state_static.json)")
+print("#")
+print("# One host per line. `include_subdomains` after the host extends the entry to")
+print("# every subdomain. Regenerate with scripts/update_hsts_preload.sh.")
+for e in entries:
+    if e.get("mode") != "force-https":
+        continue
+    print(e["name"] + (" include_subdomains" if e.get("include_subdomains") else ""))
+' > "$TARGET"
+
+echo "Updated $TARGET"
@@ -0,0 +1,36 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+PROTO="proto/url_predictor.proto"
+TARGET_DIR="src/proto"
+
+# prost-build with a vendored protoc, so no system protoc is needed
+WORK_DIR="$(mktemp -d)"
+trap 'rm -rf "$WORK_DIR"' EXIT
+
+mkdir -p "$WORK_DIR/src"
+cat > "$WORK_DIR/Cargo.toml" <<TOML
+[package]
+name = "proto-gen"
+version = "0.0.0"
+edition = "2021"
+
+[dependencies]
+prost-build = "0.13"
+protoc-bin-vendored = "3"
+TOML
+cat > "$WORK_DIR/src/main.rs" <<RUST
+fn main() {
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path().unwrap());
+    prost_build::Config::new()
+        .btree_map(["."])
+        .out_dir("$PWD/$TARGET_DIR")
+        .compile_protos(&["$PWD/$PROTO"], &["$PWD/proto"])
+        .unwrap();
+}
+RUST
+
+echo "Generating Rust types from $PROTO..."
+cargo run --quiet --manifest-path "$WORK_DIR/Cargo.toml"
+
+echo "Updated $TARGET_DIR"
@@ -1,11 +1,7 @@
 #!/usr/bin/env bash
 set -euo pipefail
 
-PSL_URL="https://publicsuffix.org/list/public_suffix_list.dat"
-TARGET="assets/public_suffix_list.dat"
-
-echo "Downloading latest Public Suffix List..."
-curl -sSf "$PSL_URL" -o "$TARGET"
-
-echo "Updated $TARGET"
-
+# Downloads the latest Public Suffix List, verifies it, reports the decisions
+# it changes and rewrites assets/public_suffix_list.dat. Arguments are passed
+# on, e.g. --dry-run; see `url_predictor psl-update --help`.
+cargo run --quiet --features "cli real-psl" --bin url_predictor -- psl-update "$@"
@@ -0,0 +1,50 @@
+//! Async classification (feature `async`)
+//!
+//! Some providers can't answer synchronously: a suffix list kept on a server,
+//! a reputation service behind a network call. [`Classifier::classify_async`]
+//! decides synchronously first, then refines that decision with the async
+//! providers, all within a timeout. If they don't finish in time the
+//! synchronous decision is returned, so a slow network never holds up the
+//! address bar.
+//!
+//! The timeout runs on a timer thread, so no particular executor is required.
+//!
+//! [`Classifier::classify_async`]: crate::Classifier::classify_async
+
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::task::Poll;
+use std::time::Duration;
+
+use crate::Reputation;
+
+/// A boxed future borrowing from the provider, as the async traits return.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Timeout for the async steps when the classifier doesn't set one.
+pub const DEFAULT_ASYNC_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// Suffix lookups that need I/O, consulted for hosts the synchronous suffix DB
+/// doesn't know (`wiki.newtld`).
+pub trait AsyncSuffixDb: Send + Sync + 'static {
+    fn has_known_suffix<'a>(&'a self, host: &'a str, allow_private: bool) -> BoxFuture<'a, bool>;
+}
+
+/// [`ReputationProvider`](crate::ReputationProvider) backed by a remote service.
+pub trait AsyncReputationProvider: Send + Sync + 'static {
+    /// `url` is the URL about to be navigated to, `host` its ASCII host.
+    fn check<'a>(&'a self, url: &'a str, host: &'a str) -> BoxFuture<'a, Reputation>;
+}
+
+/// `future`'s output, or `None` if `timeout` passes first.
+pub(crate) async fn with_timeout<F: Future>(future: F, timeout: Duration) -> Option<F::Output> {
+    let mut future = pin!(future);
+    let mut delay = futures_timer::Delay::new(timeout);
+    std::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+        Pin::new(&mut delay).poll(cx).map(|()| None)
+    })
+    .await
+}
@@ -0,0 +1,104 @@
+//! Bangs (enabled with feature = "bangs")
+//!
+//! `!w rust` and `rust !w` go straight to the bang's target search instead of
+//! round-tripping through DuckDuckGo. The dataset uses the DuckDuckGo bang JSON
+//! format, is embedded from `assets/bangs.json` and can be replaced at runtime.
+
+use once_cell::sync::Lazy;
+use percent_encoding::utf8_percent_encode;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::keyword::QUERY_COMPONENT;
+
+const EMBEDDED_DATABASE: &str = include_str!("../assets/bangs.json");
+
+/// Placeholder for the query in bang URL templates.
+const QUERY_PLACEHOLDER: &str = "{{{s}}}";
+
+static ACTIVE_DATABASE: Lazy<RwLock<Arc<BangDatabase>>> = Lazy::new(|| {
+    let db = BangDatabase::from_json(EMBEDDED_DATABASE).expect("failed to parse bang database");
+    RwLock::new(Arc::new(db))
+});
+
+/// Bangs by trigger.
+#[derive(Debug, Clone, Default)]
+pub struct BangDatabase {
+    bangs: HashMap<String, Bang>,
+    skipped: usize,
+}
+
+/// A dataset entry, in the DuckDuckGo bang JSON format.
+#[derive(Debug, Clone, Deserialize)]
+struct Bang {
+    /// Trigger, without `!`
+    #[serde(rename = "t")]
+    trigger: String,
+    /// Target URL template with `{{{s}}}` for the query
+    #[serde(rename = "u")]
+    template: String,
+    /// Host to open when no query is given
+    #[serde(rename = "d")]
+    domain: String,
+}
+
+impl BangDatabase {
+    /// Build from a JSON array of `{"t": trigger, "u": template, "d": domain}`
+    /// objects; other fields are ignored. Entries without an https template,
+    /// a domain or a one-word trigger are skipped (see [`Self::skipped`]), so
+    /// one bad entry doesn't reject the dataset; malformed JSON does.
+    pub fn from_json(data: &str) -> Result<Self, String> {
+        let entries: Vec<Bang> = serde_json::from_str(data).map_err(|e| e.to_string())?;
+        let mut bangs = HashMap::with_capacity(entries.len());
+        let mut skipped = 0;
+        for bang in entries {
+            let is_valid = !bang.trigger.is_empty()
+                && !bang.trigger.contains(char::is_whitespace)
+                && bang.template.starts_with("https://")
+                && !bang.domain.is_empty();
+            if !is_valid {
+                skipped += 1;
+                continue;
+            }
+            bangs.insert(bang.trigger.to_lowercase(), bang);
+        }
+        Ok(Self { bangs, skipped })
+    }
+
+    /// Number of dataset entries `from_json` skipped as invalid.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Target URL for `input` if it has a known bang as its first or last word.
+    pub fn resolve(&self, input: &str) -> Option<String> {
+        let words: Vec<&str> = input.split_whitespace().collect();
+        let (first, last) = (words.first()?, words.last()?);
+        let (bang, query) = match (self.lookup(first), self.lookup(last)) {
+            (Some(bang), _) => (bang, &words[1..]),
+            (None, Some(bang)) => (bang, &words[..words.len() - 1]),
+            (None, None) => return None,
+        };
+        if query.is_empty() {
+            return Some(format!("https://{}/", bang.domain));
+        }
+        let query = utf8_percent_encode(&query.join(" "), QUERY_COMPONENT).to_string();
+        Some(bang.template.replace(QUERY_PLACEHOLDER, &query))
+    }
+
+    fn lookup(&self, word: &str) -> Option<&Bang> {
+        let trigger = word.strip_prefix('!')?;
+        self.bangs.get(&trigger.to_lowercase())
+    }
+}
+
+/// Replaces the active database, e.g. with a fresher copy downloaded by the client.
+pub(crate) fn install(db: BangDatabase) {
+    *ACTIVE_DATABASE.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(db);
+}
+
+pub(crate) fn resolve(input: &str) -> Option<String> {
+    let db = ACTIVE_DATABASE.read().unwrap_or_else(|e| e.into_inner()).clone();
+    db.resolve(input)
+}
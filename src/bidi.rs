@@ -0,0 +1,64 @@
+//! IDNA bidi rule (RFC 5893)
+//!
+//! A host with any right-to-left label must keep every label unambiguous when
+//! rendered: RTL labels may not start with a digit or mix in Latin letters, and
+//! LTR labels may not start with a digit either (`1.שלום`). The `idna` crate
+//! applies the same rule today; checking it here keeps host validation from
+//! depending on that.
+//!
+//! Text fields in right-to-left locales also insert invisible directional
+//! marks around what the user types; those are stripped before classifying.
+
+use std::borrow::Cow;
+
+use unicode_bidi::{bidi_class, BidiClass};
+
+/// LRM, RLM, ALM, the embeddings and overrides, and the isolates.
+fn is_directional_mark(c: char) -> bool {
+    matches!(c, '\u{200E}' | '\u{200F}' | '\u{061C}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}')
+}
+
+/// `input` without directional marks, e.g. `example.com` for `\u{200F}example.com`.
+pub(crate) fn strip_directional_marks(input: &str) -> Cow<'_, str> {
+    if input.contains(is_directional_mark) {
+        Cow::Owned(input.chars().filter(|&c| !is_directional_mark(c)).collect())
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Whether `unicode_host` satisfies the bidi rule; hosts without RTL labels always do.
+pub(crate) fn satisfies_bidi_rule(unicode_host: &str) -> bool {
+    let labels: Vec<&str> = unicode_host.trim_end_matches('.').split('.').collect();
+    let is_bidi_domain = labels
+        .iter()
+        .flat_map(|label| label.chars())
+        .any(|c| matches!(bidi_class(c), BidiClass::R | BidiClass::AL | BidiClass::AN));
+    !is_bidi_domain || labels.iter().all(|label| label_satisfies_bidi_rule(label))
+}
+
+fn label_satisfies_bidi_rule(label: &str) -> bool {
+    let classes: Vec<BidiClass> = label.chars().map(bidi_class).collect();
+    let Some(first) = classes.first() else {
+        return true;
+    };
+    // the last class that isn't a combining mark
+    let last = classes.iter().rev().find(|c| **c != BidiClass::NSM);
+
+    match first {
+        // rules 2-4
+        BidiClass::R | BidiClass::AL => {
+            use BidiClass::*;
+            classes.iter().all(|c| matches!(c, R | AL | AN | EN | ES | CS | ET | ON | BN | NSM))
+                && matches!(last, Some(R | AL | EN | AN))
+                && !(classes.contains(&EN) && classes.contains(&AN))
+        }
+        // rules 5-6
+        BidiClass::L => {
+            use BidiClass::*;
+            classes.iter().all(|c| matches!(c, L | EN | ES | CS | ET | ON | BN | NSM)) && matches!(last, Some(L | EN))
+        }
+        // rule 1
+        _ => false,
+    }
+}
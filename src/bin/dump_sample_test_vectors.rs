@@ -0,0 +1,9 @@
+//! Prints the sample cross-platform test vectors as JSON, for the wrappers' test suites.
+//!
+//! ```sh
+//! cargo run --bin dump_sample_test_vectors > vectors.json
+//! ```
+
+fn main() {
+    println!("{}", url_predictor::sample_test_vectors_json());
+}
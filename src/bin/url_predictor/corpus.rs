@@ -0,0 +1,190 @@
+//! `url_predictor corpus`: classifies every line of an input file under one or
+//! more policies and writes a CSV or JSON Lines report, for offline quality
+//! evaluation on large corpora.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::time::Instant;
+
+use serde::Serialize;
+use serde_json::Value;
+use url_predictor::{classify_with_context, ClassifyContext, Decision, DecisionKind, Policy, PolicyPreset};
+
+pub const USAGE: &str = "\
+Usage: url_predictor corpus [OPTIONS] FILE
+
+Classifies every line of FILE under each policy and writes one record per
+input and policy, with the decision, reason and latency. A summary per policy
+goes to stderr.
+
+Options:
+  --policy FILE    Policy JSON to classify with; repeatable
+  --preset NAME    Policy preset to classify with (Default, Android, Ios,
+                   Windows, MacOs, ...); repeatable. Without --policy or
+                   --preset, Policy::default() is used
+  --context FILE   ClassifyContext JSON (locale, network_state, ...)
+  --format FORMAT  csv (default) or json, one object per line
+  --output FILE    Write the report to FILE instead of stdout
+  -h, --help       Print this help
+";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Csv,
+    Json,
+}
+
+struct Options {
+    /// Policies with the label they're reported under
+    policies: Vec<(String, Policy)>,
+    ctx: ClassifyContext,
+    format: Format,
+    output: Option<String>,
+    file: String,
+}
+
+/// One row of the report.
+#[derive(Serialize)]
+struct Record<'a> {
+    input: &'a str,
+    policy: &'a str,
+    decision: DecisionKind,
+    /// URL of Navigate and Blocked decisions, query of Search ones
+    target: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+    latency_micros: u64,
+}
+
+/// Decisions and latencies of one policy, for the summary.
+#[derive(Default)]
+struct Tally {
+    navigate: usize,
+    search: usize,
+    blocked: usize,
+    latencies: Vec<u64>,
+}
+
+pub fn run(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let Some(options) = parse_args(args)? else {
+        print!("{USAGE}");
+        return Ok(());
+    };
+    let input = File::open(&options.file).map_err(|e| format!("{}: {e}", options.file))?;
+    let output: Box<dyn Write> = match &options.output {
+        Some(path) => Box::new(File::create(path).map_err(|e| format!("{path}: {e}"))?),
+        None => Box::new(io::stdout().lock()),
+    };
+    let mut output = BufWriter::new(output);
+    let write_err = |e: io::Error| format!("writing report: {e}");
+
+    if options.format == Format::Csv {
+        writeln!(output, "input,policy,decision,target,reason,latency_micros").map_err(write_err)?;
+    }
+    // lazily built tables would otherwise count against the first input
+    for (_, policy) in &options.policies {
+        classify_with_context("example.com", policy, &options.ctx);
+    }
+    let mut tallies: Vec<Tally> = options.policies.iter().map(|_| Tally::default()).collect();
+    for line in BufReader::new(input).lines() {
+        let line = line.map_err(|e| format!("{}: {e}", options.file))?;
+        let input = line.trim_end_matches('\r');
+        for ((label, policy), tally) in options.policies.iter().zip(&mut tallies) {
+            let start = Instant::now();
+            let decision = classify_with_context(input, policy, &options.ctx);
+            let latency_micros = u64::try_from(start.elapsed().as_micros()).unwrap_or(u64::MAX);
+
+            let record = record(input, label, &decision, latency_micros);
+            match options.format {
+                Format::Csv => write_csv(&mut output, &record),
+                Format::Json => writeln!(output, "{}", serde_json::to_string(&record).unwrap_or_default()),
+            }
+            .map_err(write_err)?;
+            tally.add(record.decision, latency_micros);
+        }
+    }
+    output.flush().map_err(write_err)?;
+
+    for ((label, _), tally) in options.policies.iter().zip(&tallies) {
+        eprintln!("{}", tally.summary(label));
+    }
+    Ok(())
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut policies = Vec::new();
+    let mut ctx = ClassifyContext::default();
+    let (mut format, mut output, mut file) = (Format::Csv, None, None);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--policy" => {
+                let path = args.next().ok_or("--policy needs a file")?;
+                policies.push((path.clone(), crate::load_policy(&path)?));
+            }
+            "--preset" => {
+                let name = args.next().ok_or("--preset needs a name")?;
+                let preset: PolicyPreset = serde_json::from_value(Value::String(name.clone()))
+                    .map_err(|_| format!("unknown preset {name}"))?;
+                policies.push((name, preset.policy()));
+            }
+            "--context" => {
+                let path = args.next().ok_or("--context needs a file")?;
+                ctx = crate::load_context(&path)?;
+            }
+            "--format" => {
+                format = match args.next().as_deref() {
+                    Some("csv") => Format::Csv,
+                    Some("json") => Format::Json,
+                    _ => return Err("--format needs csv or json".into()),
+                }
+            }
+            "--output" => output = Some(args.next().ok_or("--output needs a file")?),
+            _ if arg.starts_with("--") => return Err(format!("unknown option {arg}")),
+            _ if file.is_none() => file = Some(arg),
+            _ => return Err(format!("unexpected argument {arg}")),
+        }
+    }
+    if policies.is_empty() {
+        policies.push(("default".to_string(), Policy::default()));
+    }
+    let file = file.ok_or("corpus needs an input file")?;
+    Ok(Some(Options { policies, ctx, format, output, file }))
+}
+
+fn record<'a>(input: &'a str, policy: &'a str, decision: &'a Decision, latency_micros: u64) -> Record<'a> {
+    let reason = decision.reason().map(|reason| format!("{reason:?}"));
+    Record { input, policy, decision: decision.kind(), target: crate::target(decision), reason, latency_micros }
+}
+
+fn write_csv(out: &mut impl Write, record: &Record) -> io::Result<()> {
+    let kind = format!("{:?}", record.decision);
+    let fields = [record.input, record.policy, &kind, record.target, record.reason.as_deref().unwrap_or("")];
+    writeln!(out, "{},{}", crate::csv_row(&fields), record.latency_micros)
+}
+
+impl Tally {
+    fn add(&mut self, kind: DecisionKind, latency_micros: u64) {
+        match kind {
+            DecisionKind::Navigate => self.navigate += 1,
+            DecisionKind::Search => self.search += 1,
+            DecisionKind::Blocked => self.blocked += 1,
+        }
+        self.latencies.push(latency_micros);
+    }
+
+    fn summary(&self, label: &str) -> String {
+        let mut latencies = self.latencies.clone();
+        latencies.sort_unstable();
+        let percentile = |p: usize| latencies.get((latencies.len() * p / 100).min(latencies.len().saturating_sub(1)));
+        format!(
+            "{label}: {} inputs, {} navigate, {} search, {} blocked; latency p50 {}µs, p99 {}µs",
+            latencies.len(),
+            self.navigate,
+            self.search,
+            self.blocked,
+            percentile(50).copied().unwrap_or(0),
+            percentile(99).copied().unwrap_or(0),
+        )
+    }
+}
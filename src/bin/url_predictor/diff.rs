@@ -0,0 +1,170 @@
+//! `url_predictor diff`: classifies a corpus under two configurations and
+//! reports the inputs whose decisions changed, to gate policy and public
+//! suffix list rollouts.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::sync::Arc;
+
+use serde_json::Value;
+use url_predictor::{diff_decisions, Classifier, ClassifyContext, DecisionChange, Policy, PolicyPreset, SuffixDb};
+
+pub const USAGE: &str = "\
+Usage: url_predictor diff [OPTIONS] FILE
+
+Classifies every line of FILE under the before and after configurations and
+writes the inputs whose decisions differ. Exits with 1 when any did, 0 when
+none did. A summary goes to stderr.
+
+Options:
+  --before-policy FILE, --after-policy FILE
+                   Policy JSON of each side; fields it leaves out keep their defaults
+  --before-preset NAME, --after-preset NAME
+                   Policy preset of each side (Default, Android, Ios, ...)
+  --before-psl FILE, --after-psl FILE
+                   Public suffix list snapshot of each side (real-psl builds)
+  --context FILE   ClassifyContext JSON (locale, network_state, ...)
+  --format FORMAT  csv (default) or json, one object per line
+  --output FILE    Write the report to FILE instead of stdout
+  -h, --help       Print this help
+";
+
+/// One configuration being compared.
+#[derive(Default)]
+struct Side {
+    policy: Policy,
+    db: Option<Arc<dyn SuffixDb>>,
+}
+
+impl Side {
+    fn classifier(self) -> Classifier {
+        let classifier = Classifier::new(self.policy);
+        match self.db {
+            Some(db) => classifier.with_suffix_db(db),
+            None => classifier,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Format {
+    Csv,
+    Json,
+}
+
+struct Options {
+    before: Classifier,
+    after: Classifier,
+    ctx: ClassifyContext,
+    format: Format,
+    output: Option<String>,
+    file: String,
+}
+
+/// Runs the subcommand; `Ok(true)` when some decision changed.
+pub fn run(args: impl Iterator<Item = String>) -> Result<bool, String> {
+    let Some(options) = parse_args(args)? else {
+        print!("{USAGE}");
+        return Ok(false);
+    };
+    let corpus = crate::read(&options.file)?;
+    let inputs: Vec<&str> = corpus.lines().map(|line| line.trim_end_matches('\r')).collect();
+    let changes = diff_decisions(&inputs, &options.before, &options.after, &options.ctx);
+
+    let output: Box<dyn Write> = match &options.output {
+        Some(path) => Box::new(File::create(path).map_err(|e| format!("{path}: {e}"))?),
+        None => Box::new(io::stdout().lock()),
+    };
+    let mut output = BufWriter::new(output);
+    write_report(&mut output, &changes, options.format).map_err(|e| format!("writing report: {e}"))?;
+
+    eprintln!("{} of {} inputs changed", changes.len(), inputs.len());
+    Ok(!changes.is_empty())
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let (mut before, mut after) = (Side::default(), Side::default());
+    let mut ctx = ClassifyContext::default();
+    let (mut format, mut output, mut file) = (Format::Csv, None, None);
+    while let Some(arg) = args.next() {
+        let side = if arg.starts_with("--before-") {
+            Some(&mut before)
+        } else if arg.starts_with("--after-") {
+            Some(&mut after)
+        } else {
+            None
+        };
+        match (arg.as_str(), side) {
+            ("-h" | "--help", _) => return Ok(None),
+            ("--before-policy" | "--after-policy", Some(side)) => {
+                let path = args.next().ok_or(format!("{arg} needs a file"))?;
+                side.policy = crate::load_policy(&path)?;
+            }
+            ("--before-preset" | "--after-preset", Some(side)) => {
+                let name = args.next().ok_or(format!("{arg} needs a name"))?;
+                let preset: PolicyPreset = serde_json::from_value(Value::String(name.clone()))
+                    .map_err(|_| format!("unknown preset {name}"))?;
+                side.policy = preset.policy();
+            }
+            ("--before-psl" | "--after-psl", Some(side)) => {
+                let path = args.next().ok_or(format!("{arg} needs a file"))?;
+                side.db = Some(load_psl(&path)?);
+            }
+            ("--context", _) => ctx = crate::load_context(&args.next().ok_or("--context needs a file")?)?,
+            ("--format", _) => {
+                format = match args.next().as_deref() {
+                    Some("csv") => Format::Csv,
+                    Some("json") => Format::Json,
+                    _ => return Err("--format needs csv or json".into()),
+                }
+            }
+            ("--output", _) => output = Some(args.next().ok_or("--output needs a file")?),
+            _ if arg.starts_with("--") => return Err(format!("unknown option {arg}")),
+            _ if file.is_none() => file = Some(arg),
+            _ => return Err(format!("unexpected argument {arg}")),
+        }
+    }
+    let file = file.ok_or("diff needs an input file")?;
+    Ok(Some(Options { before: before.classifier(), after: after.classifier(), ctx, format, output, file }))
+}
+
+#[cfg(feature = "real-psl")]
+fn load_psl(path: &str) -> Result<Arc<dyn SuffixDb>, String> {
+    let db = url_predictor::RealSuffixDb::from_psl_file(std::path::Path::new(path))?;
+    Ok(Arc::new(db))
+}
+
+#[cfg(not(feature = "real-psl"))]
+fn load_psl(_path: &str) -> Result<Arc<dyn SuffixDb>, String> {
+    Err("PSL snapshots need a build with the real-psl feature".into())
+}
+
+pub(crate) fn write_report(out: &mut impl Write, changes: &[DecisionChange], format: Format) -> io::Result<()> {
+    if format == Format::Csv {
+        writeln!(out, "input,before,before_target,before_reason,after,after_target,after_reason")?;
+    }
+    for change in changes {
+        match format {
+            Format::Csv => {
+                let (before_kind, after_kind) =
+                    (format!("{:?}", change.before.kind()), format!("{:?}", change.after.kind()));
+                let (before_reason, after_reason) = (
+                    change.before.reason().map(|r| format!("{r:?}")).unwrap_or_default(),
+                    change.after.reason().map(|r| format!("{r:?}")).unwrap_or_default(),
+                );
+                let row = crate::csv_row(&[
+                    &change.input,
+                    &before_kind,
+                    crate::target(&change.before),
+                    &before_reason,
+                    &after_kind,
+                    crate::target(&change.after),
+                    &after_reason,
+                ]);
+                writeln!(out, "{row}")?;
+            }
+            Format::Json => writeln!(out, "{}", serde_json::to_string(change).unwrap_or_default())?,
+        }
+    }
+    out.flush()
+}
@@ -0,0 +1,252 @@
+//! Classifies address bar input from the command line, for triaging reports
+//! like "why did X search instead of navigate?" (feature `cli`).
+//!
+//! ```sh
+//! cargo run --features cli --bin url_predictor -- --explain "wiki.corp/page"
+//! cargo run --features cli --bin url_predictor -- --policy android.json   # REPL
+//! cargo run --features cli --bin url_predictor -- corpus --preset Android inputs.txt > report.csv
+//! cargo run --features "cli real-psl" --bin url_predictor -- psl-update
+//! ```
+
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+
+use serde_json::Value;
+use url_predictor::{explain, ClassifyContext, DecidedBy, Decision, Explanation, Policy};
+
+mod corpus;
+mod diff;
+mod psl_update;
+
+const USAGE: &str = "\
+Usage: url_predictor [OPTIONS] [INPUT]...
+       url_predictor corpus [OPTIONS] FILE
+       url_predictor diff [OPTIONS] FILE
+       url_predictor psl-update [OPTIONS]
+
+Classifies each INPUT as a navigation or a search. Without inputs, reads one
+input per line from stdin, interactively when it is a terminal.
+
+Options:
+  --policy FILE    Policy JSON; fields it leaves out keep their defaults
+  --context FILE   ClassifyContext JSON (locale, network_state, ...)
+  --explain        Show the rules tried and the one that decided
+  --json           Print one JSON object per input
+  -h, --help       Print this help
+
+`url_predictor SUBCOMMAND --help` describes the corpus, diff and psl-update
+subcommands.
+";
+
+struct Options {
+    policy: Policy,
+    ctx: ClassifyContext,
+    explain: bool,
+    json: bool,
+    inputs: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).peekable();
+    match args.peek().map(String::as_str) {
+        Some("corpus") => {
+            args.next();
+            return match corpus::run(args) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("url_predictor corpus: {e}\n\n{}", corpus::USAGE);
+                    ExitCode::from(2)
+                }
+            };
+        }
+        Some("diff") => {
+            args.next();
+            return match diff::run(args) {
+                // like diff(1): 1 when something changed
+                Ok(changed) => ExitCode::from(u8::from(changed)),
+                Err(e) => {
+                    eprintln!("url_predictor diff: {e}\n\n{}", diff::USAGE);
+                    ExitCode::from(2)
+                }
+            };
+        }
+        Some("psl-update") => {
+            args.next();
+            return match psl_update::run(args) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("url_predictor psl-update: {e}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        _ => {}
+    }
+
+    let options = match parse_args(args) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("url_predictor: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    if !options.inputs.is_empty() {
+        for input in &options.inputs {
+            println!("{}", render(input, &options));
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    let mut stdout = io::stdout();
+    loop {
+        if interactive {
+            print!("> ");
+            let _ = stdout.flush();
+        }
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("url_predictor: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+        let input = line.trim_end_matches(['\n', '\r']);
+        if interactive && input.is_empty() {
+            continue;
+        }
+        println!("{}", render(input, &options));
+    }
+    ExitCode::SUCCESS
+}
+
+/// The options, or `None` when help was asked for.
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options =
+        Options { policy: Policy::default(), ctx: ClassifyContext::default(), explain: false, json: false, inputs: Vec::new() };
+    let mut args = args.peekable();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--explain" => options.explain = true,
+            "--json" => options.json = true,
+            "--policy" => options.policy = load_policy(&args.next().ok_or("--policy needs a file")?)?,
+            "--context" => {
+                options.ctx = load_context(&args.next().ok_or("--context needs a file")?)?;
+            }
+            "--" => options.inputs.extend(args.by_ref()),
+            _ if arg.starts_with("--") => return Err(format!("unknown option {arg}")),
+            _ => options.inputs.push(arg),
+        }
+    }
+    Ok(Some(options))
+}
+
+/// Reads a policy file, merging its fields over `Policy::default()`.
+fn load_policy(path: &str) -> Result<Policy, String> {
+    let overrides: Value = serde_json::from_str(&read(path)?).map_err(|e| format!("{path}: {e}"))?;
+    let Value::Object(overrides) = overrides else {
+        return Err(format!("{path}: expected a JSON object"));
+    };
+    let mut policy = serde_json::to_value(Policy::default()).map_err(|e| e.to_string())?;
+    if let Value::Object(policy) = &mut policy {
+        policy.extend(overrides);
+    }
+    let policy: Policy = serde_json::from_value(policy).map_err(|e| format!("{path}: {e}"))?;
+    policy.validate_keywords().map_err(|e| format!("{path}: {e}"))?;
+    Ok(policy)
+}
+
+fn load_context(path: &str) -> Result<ClassifyContext, String> {
+    serde_json::from_str(&read(path)?).map_err(|e| format!("{path}: {e}"))
+}
+
+fn read(path: &str) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))
+}
+
+/// URL of Navigate and Blocked decisions, query of Search ones.
+fn target(decision: &Decision) -> &str {
+    match decision {
+        Decision::Navigate { url, .. } | Decision::Blocked { url, .. } => url,
+        Decision::Search { query, .. } => query,
+    }
+}
+
+/// `fields` as a CSV row, quoting fields with a delimiter, quote or line
+/// break (RFC 4180).
+fn csv_row(fields: &[&str]) -> String {
+    let quoted: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect();
+    quoted.join(",")
+}
+
+fn render(input: &str, options: &Options) -> String {
+    let explanation = explain(input, &options.policy, &options.ctx);
+    match (options.json, options.explain) {
+        (true, true) => serde_json::to_string(&explanation).unwrap_or_default(),
+        (true, false) => serde_json::to_string(&explanation.decision).unwrap_or_default(),
+        (false, explain) => render_text(&explanation, explain),
+    }
+}
+
+fn render_text(explanation: &Explanation, explain: bool) -> String {
+    let mut out = String::new();
+    if explain {
+        for rule in &explanation.rules_tried {
+            let decided = explanation.decided_by == DecidedBy::Rule(*rule);
+            out.push_str(&format!("  {:<24} {}\n", format!("{rule:?}"), if decided { "matched" } else { "-" }));
+        }
+        match explanation.decided_by {
+            DecidedBy::Rule(_) => {}
+            DecidedBy::Heuristic => out.push_str("  decided by a heuristic\n"),
+            DecidedBy::Fallback => out.push_str("  no rule matched, searching\n"),
+        }
+        match (&explanation.host, &explanation.suffix_rule) {
+            (Some(host), Some(rule)) => out.push_str(&format!("  suffix of {host}: PSL rule {} ({:?})\n", rule.rule, rule.section)),
+            (Some(host), None) => out.push_str(&format!("  suffix of {host}: no PSL rule\n")),
+            (None, _) => {}
+        }
+    }
+    let mut details = Vec::new();
+    let summary = match &explanation.decision {
+        Decision::Navigate { url, search_alternative, warnings, transforms, .. } => {
+            details.extend(search_alternative.iter().map(|q| format!("search alternative: {q}")));
+            details.extend(warnings.iter().map(|w| format!("warning: {w:?}")));
+            details.extend(transforms.iter().map(|t| format!("transform: {t:?}")));
+            format!("Navigate {url}")
+        }
+        Decision::Search { query, unknown_scheme_navigation, reason, did_you_mean, idna_error, .. } => {
+            details.extend(reason.iter().map(|r| format!("reason: {r:?}")));
+            details.extend(idna_error.iter().map(|e| format!("idna error: {e:?}")));
+            details.extend(unknown_scheme_navigation.iter().map(|u| format!("unknown scheme navigation: {u}")));
+            details.extend(did_you_mean.iter().map(|u| format!("did you mean: {u}")));
+            format!("Search {query:?}")
+        }
+        Decision::Blocked { url, threat, reason } => {
+            details.extend(reason.iter().map(|r| format!("reason: {r:?}")));
+            format!("Blocked {url} ({threat})")
+        }
+    };
+    out.push_str(&summary);
+    for detail in details {
+        out.push_str(&format!("\n  {detail}"));
+    }
+    out
+}
@@ -0,0 +1,184 @@
+//! `url_predictor psl-update`: refreshes the vendored public suffix list in one
+//! verified step. Downloads the list, checks that it parses and knows the
+//! suffixes every list has, reports the corpus decisions it changes, and
+//! rewrites `assets/public_suffix_list.dat` with a header recording the refresh.
+
+use std::io::{self, BufWriter};
+use std::process::Command;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use url_predictor::{diff_decisions, Classifier, ClassifyContext, Policy, SuffixDb};
+
+use crate::diff::{write_report, Format};
+
+pub const USAGE: &str = "\
+Usage: url_predictor psl-update [OPTIONS]
+
+Downloads the latest public suffix list, checks that it parses, classifies a
+corpus with the vendored and the downloaded list, writes the decisions that
+change to stdout as CSV, and rewrites the vendored list with a header
+recording the refresh. Needs a build with the real-psl feature.
+
+Options:
+  --url URL        Where to download the list (default: publicsuffix.org)
+  --from FILE      Use a list already on disk instead of downloading
+  --corpus FILE    Inputs to compare decisions on (default: tests/golden/corpus.txt)
+  --policy FILE    Policy JSON to classify with; fields it leaves out keep their defaults
+  --output FILE    Vendored list to compare against and rewrite
+                   (default: assets/public_suffix_list.dat)
+  --dry-run        Report without rewriting the vendored list
+  -h, --help       Print this help
+";
+
+const PSL_URL: &str = "https://publicsuffix.org/list/public_suffix_list.dat";
+
+/// Starts the header lines this subcommand writes, so the next run replaces them.
+const HEADER_PREFIX: &str = "// url_predictor:";
+
+/// Hosts under suffixes any real list has, ICANN and private.
+const KNOWN_HOSTS: &[(&str, bool)] = &[("example.com", false), ("example.co.uk", false), ("example.github.io", true)];
+
+struct Options {
+    source: Source,
+    corpus: String,
+    policy: Policy,
+    output: String,
+    dry_run: bool,
+}
+
+enum Source {
+    Url(String),
+    File(String),
+}
+
+pub fn run(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let Some(options) = parse_args(args)? else {
+        print!("{USAGE}");
+        return Ok(());
+    };
+    let (list, origin) = match &options.source {
+        Source::Url(url) => (download(url)?, url.as_str()),
+        Source::File(path) => (crate::read(path)?, path.as_str()),
+    };
+    let after = verify(&list).map_err(|e| format!("{origin}: {e}"))?;
+    let vendored = crate::read(&options.output)?;
+    let before = parse_psl(&vendored).map_err(|e| format!("{}: {e}", options.output))?;
+
+    let corpus = crate::read(&options.corpus)?;
+    let inputs: Vec<&str> = corpus.lines().map(|line| line.trim_end_matches('\r')).collect();
+    let classifier = |db| Classifier::new(options.policy.clone()).with_suffix_db(db);
+    let changes = diff_decisions(&inputs, &classifier(before), &classifier(after), &ClassifyContext::default());
+    write_report(&mut BufWriter::new(io::stdout().lock()), &changes, Format::Csv)
+        .map_err(|e| format!("writing report: {e}"))?;
+    eprintln!("{} of {} corpus inputs change with the new list", changes.len(), inputs.len());
+
+    let updated = with_header(&list, origin);
+    if updated == vendored {
+        eprintln!("{} is up to date", options.output);
+    } else if options.dry_run {
+        eprintln!("dry run, {} left as is", options.output);
+    } else {
+        // written next to the target and renamed over it, so a failed write
+        // never leaves a truncated list behind
+        let staged = format!("{}.new", options.output);
+        std::fs::write(&staged, &updated).map_err(|e| format!("{staged}: {e}"))?;
+        std::fs::rename(&staged, &options.output).map_err(|e| format!("{}: {e}", options.output))?;
+        eprintln!("updated {}", options.output);
+    }
+    Ok(())
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        source: Source::Url(PSL_URL.to_string()),
+        corpus: "tests/golden/corpus.txt".to_string(),
+        policy: Policy::default(),
+        output: "assets/public_suffix_list.dat".to_string(),
+        dry_run: false,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--url" => options.source = Source::Url(args.next().ok_or("--url needs a URL")?),
+            "--from" => options.source = Source::File(args.next().ok_or("--from needs a file")?),
+            "--corpus" => options.corpus = args.next().ok_or("--corpus needs a file")?,
+            "--policy" => options.policy = crate::load_policy(&args.next().ok_or("--policy needs a file")?)?,
+            "--output" => options.output = args.next().ok_or("--output needs a file")?,
+            "--dry-run" => options.dry_run = true,
+            _ => return Err(format!("unexpected argument {arg}")),
+        }
+    }
+    Ok(Some(options))
+}
+
+/// Fetches `url` with curl, like the scripts in `scripts/`.
+fn download(url: &str) -> Result<String, String> {
+    eprintln!("downloading {url}");
+    let output = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--location", url])
+        .output()
+        .map_err(|e| format!("running curl: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("downloading {url}: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    String::from_utf8(output.stdout).map_err(|_| format!("{url}: not UTF-8"))
+}
+
+/// Parses `list` and checks it is a whole list rather than an error page or
+/// a truncated download.
+fn verify(list: &str) -> Result<Arc<dyn SuffixDb>, String> {
+    for marker in ["===BEGIN ICANN DOMAINS===", "===END ICANN DOMAINS===", "===BEGIN PRIVATE DOMAINS===", "===END PRIVATE DOMAINS==="] {
+        if !list.contains(marker) {
+            return Err(format!("no {marker} marker, not a complete list"));
+        }
+    }
+    let db = parse_psl(list)?;
+    if let Some((host, _)) = KNOWN_HOSTS.iter().find(|(host, private)| !db.has_known_suffix(host, *private)) {
+        return Err(format!("{host} has no known suffix"));
+    }
+    Ok(db)
+}
+
+#[cfg(feature = "real-psl")]
+fn parse_psl(list: &str) -> Result<Arc<dyn SuffixDb>, String> {
+    Ok(Arc::new(url_predictor::RealSuffixDb::from_psl_string(list)?))
+}
+
+#[cfg(not(feature = "real-psl"))]
+fn parse_psl(_list: &str) -> Result<Arc<dyn SuffixDb>, String> {
+    Err("psl-update needs a build with the real-psl feature".into())
+}
+
+/// `list` with its `url_predictor` header, replacing the one from the last refresh.
+fn with_header(list: &str, origin: &str) -> String {
+    let body: String = list.lines().skip_while(|line| line.starts_with(HEADER_PREFIX)).map(|line| format!("{line}\n")).collect();
+    let vendored = vendored_header(&body, origin);
+    format!("{vendored}{body}")
+}
+
+fn vendored_header(body: &str, origin: &str) -> String {
+    let version = body.lines().find_map(|line| line.strip_prefix("// VERSION: ")).unwrap_or("unknown");
+    let rules = body.lines().filter(|line| !line.is_empty() && !line.starts_with("//")).count();
+    format!(
+        "{HEADER_PREFIX} vendored {} from {origin}\n\
+         {HEADER_PREFIX} upstream version {version}, {rules} rules; refresh with `url_predictor psl-update`\n",
+        today()
+    )
+}
+
+/// Today's UTC date as YYYY-MM-DD.
+fn today() -> String {
+    let days = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() / 86_400);
+    // days since 1970-01-01 to a civil date, from Howard Hinnant's `civil_from_days`
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
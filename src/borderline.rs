@@ -0,0 +1,46 @@
+//! Borderline hosts
+//!
+//! Host-like inputs whose suffix the suffix DB doesn't know search, but an
+//! [`AsyncSuffixDb`](crate::AsyncSuffixDb) or a DNS probe may vouch for them.
+//! They are classified again with the vouched-for host treated as having a
+//! known suffix, so the decision is post-processed like any other Navigate.
+
+use std::sync::Arc;
+
+use url::Url;
+
+use crate::{host_like_valid, to_idna_ascii, SuffixDb, SuffixRule};
+
+/// The ASCII host of a schemeless, host-like `input` whose suffix `db` doesn't
+/// know (`wiki.corp`).
+pub(crate) fn unknown_suffix_host(input: &str, db: &dyn SuffixDb, allow_private: bool) -> Option<String> {
+    let input = input.trim();
+    if input.contains(char::is_whitespace) || input.contains("://") {
+        return None;
+    }
+    let u = Url::parse(&format!("http://{input}")).ok()?;
+    let host = to_idna_ascii(u.host_str()?)?.to_ascii_lowercase();
+    let host = host.trim_end_matches('.');
+    let unknown = host.contains('.') && host_like_valid(host) && !db.has_known_suffix(host, allow_private);
+    unknown.then(|| host.to_string())
+}
+
+/// A classifier's suffix DB, plus a host that was vouched for.
+pub(crate) struct KnownSuffix {
+    pub(crate) inner: Arc<dyn SuffixDb>,
+    pub(crate) host: String,
+}
+
+impl SuffixDb for KnownSuffix {
+    fn has_known_suffix(&self, host: &str, allow_private: bool) -> bool {
+        host.trim_end_matches('.').eq_ignore_ascii_case(&self.host) || self.inner.has_known_suffix(host, allow_private)
+    }
+
+    fn registrable_domain(&self, host: &str) -> Option<String> {
+        self.inner.registrable_domain(host)
+    }
+
+    fn matching_rule(&self, host: &str) -> Option<SuffixRule> {
+        self.inner.matching_rule(host)
+    }
+}
@@ -0,0 +1,55 @@
+//! Chromium omnibox compatibility (`PlatformParity::Chromium`)
+//!
+//! Chromium's URL fixer repairs a few typing slips before classifying, and a
+//! single word that could be an intranet host is searched for while the browser
+//! probes it, showing a "Did you mean http://word/?" infobar when it resolves.
+
+use std::borrow::Cow;
+use std::net::Ipv6Addr;
+
+/// Schemes whose `;//` typo the fixer repairs.
+const FIXABLE_SCHEMES: &[&str] = &["http", "https", "ftp", "file"];
+
+/// Repairs `http;//example.com` and completes the URL after `view-source:`.
+pub(crate) fn fix_up(input: &str) -> Cow<'_, str> {
+    if let Some((scheme, rest)) = input.split_once(";//") {
+        if FIXABLE_SCHEMES.iter().any(|s| s.eq_ignore_ascii_case(scheme)) {
+            return Cow::Owned(format!("{scheme}://{rest}"));
+        }
+    }
+    if let Some(inner) = input.get(..12).filter(|p| p.eq_ignore_ascii_case("view-source:")).map(|_| &input[12..]) {
+        // `view-source:example.com:8080` has a port, not a scheme
+        let has_scheme =
+            inner.split_once(':').is_some_and(|(_, rest)| !rest.starts_with(|c: char| c.is_ascii_digit()));
+        if !has_scheme && has_host(inner) {
+            return Cow::Owned(format!("view-source:http://{}", fix_up(inner)));
+        }
+    }
+    Cow::Borrowed(input)
+}
+
+/// Whether schemeless `input` starts with something that can be a host
+/// (`example.com/a`, `localhost:8080`), rather than `%20%`.
+fn has_host(input: &str) -> bool {
+    let authority = input.split(['/', '?', '#']).next().unwrap_or(input);
+    if let Some(bracketed) = authority.strip_prefix('[') {
+        return bracketed.split_once(']').is_some_and(|(ip, _)| ip.parse::<Ipv6Addr>().is_ok());
+    }
+    let host = match authority.rsplit_once(':') {
+        Some((host, port)) if port.bytes().all(|b| b.is_ascii_digit()) => host,
+        _ => authority,
+    };
+    crate::to_idna_ascii(host).is_some_and(|ascii| crate::host_like_valid(&ascii))
+}
+
+/// The intranet URL to offer for a single word, e.g. `http://wiki/` for `wiki`.
+pub(crate) fn did_you_mean(input: &str) -> Option<String> {
+    if input.is_empty() || input.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if input.contains(|c: char| c.is_whitespace() || matches!(c, '.' | ':' | '/' | '@' | '?' | '#')) {
+        return None;
+    }
+    let ascii = crate::to_idna_ascii(input)?.to_ascii_lowercase();
+    crate::host_like_valid(&ascii).then(|| format!("http://{ascii}/"))
+}
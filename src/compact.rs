@@ -0,0 +1,25 @@
+//! CBOR payloads for the FFI and JNI entry points
+//!
+//! Same schema as the JSON entry points (serde's externally tagged enums,
+//! optional fields omitted), in a binary encoding that is cheaper to produce
+//! and parse on every keystroke.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Decodes a CBOR `what` (for the log message), falling back to its default.
+pub(crate) fn decode_or_default<T: DeserializeOwned + Default>(bytes: &[u8], what: &str) -> T {
+    ciborium::from_reader(bytes).unwrap_or_else(|e| {
+        eprintln!("url_predictor: {what} CBOR parse error: {e}. Using defaults.");
+        T::default()
+    })
+}
+
+/// CBOR encoding of `value`; empty if it can't be encoded.
+pub(crate) fn encode<T: Serialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    match ciborium::into_writer(value, &mut bytes) {
+        Ok(()) => bytes,
+        Err(_) => Vec::new(),
+    }
+}
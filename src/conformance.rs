@@ -0,0 +1,71 @@
+//! WHATWG URL conformance harness (tests only)
+//!
+//! Navigate URLs are assembled from string rewrites (userinfo stripping, file
+//! path encoding, IPv4 octet filling, bang and keyword templates) as well as
+//! from `url`. Every one must be a URL a browser parses back to itself, or the
+//! address bar would navigate somewhere other than what was displayed.
+//!
+//! Two checks: the `url` crate (a WHATWG implementation) must reparse the URL to
+//! the same string, twice over, and an independent check of the serialized form
+//! catches anything `url` would quietly repair, such as hosts that aren't
+//! canonical or raw characters the serializer always percent-encodes.
+
+use std::net::Ipv4Addr;
+
+use url::Url;
+
+/// Schemes with a host in WHATWG terms.
+const SPECIAL_SCHEMES: &[&str] = &["http", "https", "ftp", "ws", "wss", "file"];
+
+/// Why `url` isn't a conforming Navigate URL, or `Ok` if it is.
+pub(crate) fn check_navigate_url(url: &str) -> Result<(), String> {
+    let parsed = Url::parse(url).map_err(|e| format!("{url:?} doesn't parse: {e}"))?;
+    if parsed.as_str() != url {
+        return Err(format!("{url:?} reparses to {:?}", parsed.as_str()));
+    }
+    let reparsed = Url::parse(parsed.as_str()).map_err(|e| format!("{url:?} doesn't reparse: {e}"))?;
+    if reparsed != parsed {
+        return Err(format!("{url:?} isn't idempotent: {:?}", reparsed.as_str()));
+    }
+    check_serialization(url)
+}
+
+fn check_serialization(url: &str) -> Result<(), String> {
+    if let Some(c) = url.chars().find(|c| c.is_ascii_control() || !c.is_ascii()) {
+        return Err(format!("{url:?} has a raw {c:?}"));
+    }
+    let (scheme, rest) = url.split_once(':').ok_or_else(|| format!("{url:?} has no scheme"))?;
+    if scheme.bytes().any(|b| b.is_ascii_uppercase()) {
+        return Err(format!("{url:?} has an uppercase scheme"));
+    }
+    if url.contains(' ') {
+        return Err(format!("{url:?} has a raw ' '"));
+    }
+    if !SPECIAL_SCHEMES.contains(&scheme) {
+        return Ok(());
+    }
+
+    let authority = rest.strip_prefix("//").ok_or_else(|| format!("{url:?} has no authority"))?;
+    let authority = &authority[..authority.find(['/', '?', '#']).unwrap_or(authority.len())];
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, host_port)| host_port);
+    let host = match host_port.strip_prefix('[') {
+        Some(ipv6) if ipv6.contains(']') => return Ok(()),
+        Some(_) => return Err(format!("{url:?} has an unclosed IPv6 host")),
+        None => host_port.split_once(':').map_or(host_port, |(host, _)| host),
+    };
+    if host.is_empty() {
+        return if scheme == "file" { Ok(()) } else { Err(format!("{url:?} has an empty host")) };
+    }
+    if host.bytes().any(|b| b.is_ascii_uppercase()) {
+        return Err(format!("{url:?} has an uppercase host"));
+    }
+    if host.contains("..") || host.starts_with('.') {
+        return Err(format!("{url:?} has an empty label"));
+    }
+    // a host ending in a number is an IPv4 address, which must be in dotted-quad form
+    let last_label = host.trim_end_matches('.').rsplit('.').next().unwrap_or_default();
+    if !last_label.is_empty() && last_label.bytes().all(|b| b.is_ascii_digit()) && host.parse::<Ipv4Addr>().is_err() {
+        return Err(format!("{url:?} has a non-canonical IPv4 host"));
+    }
+    Ok(())
+}
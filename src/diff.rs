@@ -0,0 +1,35 @@
+//! Decision diffs
+//!
+//! A policy change or a new public suffix list snapshot can flip decisions in
+//! ways no unit test anticipates. [`diff_decisions`] classifies a corpus under
+//! the current and the candidate configuration and keeps only the inputs
+//! whose decisions differ, so a rollout can be gated on reviewing them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Classifier, ClassifyContext, Decision};
+
+/// An input whose decision differs between two configurations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecisionChange {
+    pub input: String,
+    pub before: Decision,
+    pub after: Decision,
+}
+
+/// Classifies each of `inputs` with `before` and `after` and returns the
+/// inputs whose decisions differ in any field, in input order.
+pub fn diff_decisions<I, S>(inputs: I, before: &Classifier, after: &Classifier, ctx: &ClassifyContext) -> Vec<DecisionChange>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    inputs
+        .into_iter()
+        .filter_map(|input| {
+            let input = input.as_ref();
+            let (before, after) = (before.classify_with_context(input, ctx), after.classify_with_context(input, ctx));
+            (before != after).then(|| DecisionChange { input: input.to_string(), before, after })
+        })
+        .collect()
+}
@@ -0,0 +1,106 @@
+//! DNS resolution probe (feature `dns-probe`)
+//!
+//! A host-like input with a suffix the PSL doesn't know (`wiki.corp`,
+//! `printer.lan`) searches, yet it may well be a host on the user's network.
+//! With a [`Resolver`] on the classifier, such inputs navigate if the host
+//! resolves within a tight timeout. Hosts that don't are remembered for a while
+//! so repeated keystrokes don't probe again.
+//!
+//! Like Chromium's intranet redirect detector, the probe first checks that the
+//! network doesn't resolve every name (ISPs that redirect NXDOMAIN to an ad
+//! page): if made-up hosts resolve, probes can't tell anything and are skipped.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use once_cell::sync::OnceCell;
+
+/// How long a probe may take before the input searches.
+pub const DNS_PROBE_TIMEOUT: Duration = Duration::from_millis(150);
+
+/// How long a host that didn't resolve is remembered.
+pub const DNS_NEGATIVE_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Answers whether a host resolves.
+pub trait Resolver: Send + Sync + 'static {
+    /// Whether `host` (ASCII, lowercase) has an address. Runs on a worker
+    /// thread, which is abandoned if it doesn't return within the timeout.
+    fn resolves(&self, host: &str) -> bool;
+}
+
+/// Resolves with the system resolver (`getaddrinfo`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemResolver;
+
+impl Resolver for SystemResolver {
+    fn resolves(&self, host: &str) -> bool {
+        std::net::ToSocketAddrs::to_socket_addrs(&(host, 80)).is_ok_and(|mut addrs| addrs.next().is_some())
+    }
+}
+
+/// A [`Resolver`] with the probe timeout, negative cache and NXDOMAIN
+/// redirection check.
+pub(crate) struct DnsProbe {
+    resolver: Arc<dyn Resolver>,
+    timeout: Duration,
+    /// Hosts that didn't resolve, and when to forget them
+    negative: Mutex<HashMap<String, Instant>>,
+    /// Whether made-up hosts resolve, checked on the first probe
+    redirects_nxdomain: OnceCell<bool>,
+}
+
+impl DnsProbe {
+    pub(crate) fn new(resolver: Arc<dyn Resolver>, timeout: Duration) -> Self {
+        Self { resolver, timeout, negative: Mutex::new(HashMap::new()), redirects_nxdomain: OnceCell::new() }
+    }
+
+    /// Whether `host` resolved within the timeout.
+    pub(crate) fn resolves(&self, host: &str) -> bool {
+        let now = Instant::now();
+        {
+            let mut negative = self.negative.lock().unwrap_or_else(|e| e.into_inner());
+            negative.retain(|_, expiry| *expiry > now);
+            if negative.contains_key(host) {
+                return false;
+            }
+        }
+        if *self.redirects_nxdomain.get_or_init(|| self.detect_nxdomain_redirection()) {
+            return false;
+        }
+        let resolved = self.resolve_with_timeout(host);
+        if !resolved {
+            let mut negative = self.negative.lock().unwrap_or_else(|e| e.into_inner());
+            negative.insert(host.to_string(), now + DNS_NEGATIVE_CACHE_TTL);
+        }
+        resolved
+    }
+
+    fn resolve_with_timeout(&self, host: &str) -> bool {
+        let (tx, rx) = mpsc::channel();
+        let resolver = Arc::clone(&self.resolver);
+        let host = host.to_string();
+        let spawned = std::thread::Builder::new()
+            .name("url_predictor-dns-probe".into())
+            .spawn(move || {
+                let _ = tx.send(resolver.resolves(&host));
+            });
+        spawned.is_ok() && rx.recv_timeout(self.timeout).unwrap_or(false)
+    }
+
+    /// Resolves three random single-label hosts, as Chromium does; any of
+    /// them resolving means the network answers for names that don't exist.
+    fn detect_nxdomain_redirection(&self) -> bool {
+        let random = RandomState::new();
+        (0..3u64).any(|i| {
+            let label: String = format!("{:016x}", random.hash_one(i))
+                .bytes()
+                .map(|b| (b'a' + b % 26) as char)
+                .collect();
+            self.resolve_with_timeout(&label)
+        })
+    }
+}
@@ -0,0 +1,100 @@
+//! Decision explanations
+//!
+//! [`explain`](crate::explain) classifies like
+//! [`classify_with_context`](crate::classify_with_context) and also reports
+//! which built-in rules ran and which one decided, for triaging "why did this
+//! search instead of navigate?" without a debugger. The public suffix list
+//! rule behind the host's suffix is reported too, as demo and real PSL
+//! decisions often differ on it.
+
+use std::cell::RefCell;
+
+use serde::{Deserialize, Serialize};
+use url::{Host, Url};
+
+use crate::{Decision, Rule, SuffixDb, SuffixRule};
+
+/// What produced the decision before post-processing (HSTS upgrade, offline
+/// handling, reputation checks, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecidedBy {
+    /// A [`Heuristic`](crate::Heuristic) registered on the classifier
+    Heuristic,
+    /// A built-in rule matched
+    Rule(Rule),
+    /// No rule matched, so the input is searched for
+    Fallback,
+}
+
+/// A decision and how it was reached.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Explanation {
+    /// Built-in rules tried, in order; the last one decided unless `decided_by` says otherwise
+    pub rules_tried: Vec<Rule>,
+    pub decided_by: DecidedBy,
+    pub decision: Decision,
+    /// Host whose suffix the decision turned on: the navigated host, or the
+    /// host a dotted search looked like
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// Rule of the suffix DB behind `host`'s suffix; `None` when no listed
+    /// rule matches or the suffix DB doesn't report rules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suffix_rule: Option<SuffixRule>,
+}
+
+/// Rules tried by the classification in progress, recorded through `Hooks`.
+#[derive(Debug, Default)]
+pub(crate) struct Trace {
+    rules_tried: RefCell<Vec<Rule>>,
+    decided_by: RefCell<Option<DecidedBy>>,
+}
+
+impl Trace {
+    /// Starts over, for a classification that decides again (transitional
+    /// IDNA mapping falling back to the input as typed).
+    pub(crate) fn reset(&self) {
+        self.rules_tried.borrow_mut().clear();
+        *self.decided_by.borrow_mut() = None;
+    }
+
+    pub(crate) fn tried(&self, rule: Rule) {
+        self.rules_tried.borrow_mut().push(rule);
+    }
+
+    pub(crate) fn decided(&self, by: DecidedBy) {
+        *self.decided_by.borrow_mut() = Some(by);
+    }
+
+    /// The explanation of `decision`, made for `input` with `db`.
+    pub(crate) fn into_explanation(self, input: &str, decision: Decision, db: &dyn SuffixDb) -> Explanation {
+        let host = suffix_host(input, &decision);
+        Explanation {
+            rules_tried: self.rules_tried.into_inner(),
+            decided_by: self.decided_by.into_inner().unwrap_or(DecidedBy::Fallback),
+            decision,
+            suffix_rule: host.as_deref().and_then(|host| db.matching_rule(host)),
+            host,
+        }
+    }
+}
+
+/// The domain `decision` navigates to or blocks, or for searches the domain
+/// `input` would have navigated to.
+fn suffix_host(input: &str, decision: &Decision) -> Option<String> {
+    let url = match decision {
+        Decision::Navigate { url, .. } | Decision::Blocked { url, .. } => Url::parse(url).ok()?,
+        Decision::Search { .. } => {
+            let input = input.trim();
+            if !input.contains('.') || input.contains(char::is_whitespace) {
+                return None;
+            }
+            let absolute = if input.contains("://") { input.to_string() } else { format!("http://{input}") };
+            Url::parse(&absolute).ok()?
+        }
+    };
+    match url.host()? {
+        Host::Domain(domain) => Some(domain.trim_end_matches('.').to_string()),
+        Host::Ipv4(_) | Host::Ipv6(_) => None,
+    }
+}
@@ -0,0 +1,125 @@
+//! Decision feedback
+//!
+//! Clients report what the user ended up doing with a decision, so thresholds
+//! can be tuned on real correction rates. Only counters over categorical data
+//! are kept: the input is reduced to its [`InputShape`] and dropped.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::DecisionKind;
+
+/// What the user did after a decision was shown.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum UserAction {
+    /// Loaded a URL, as typed or after editing the input
+    Navigated,
+    /// Ran a search, as typed or after editing the input
+    Searched,
+    /// Left the address bar without doing either
+    Abandoned,
+}
+
+impl UserAction {
+    /// Whether doing this overrode a `chosen` decision: a Search edited into a
+    /// navigation, a Navigate searched instead, or a Blocked URL loaded anyway.
+    pub fn corrects(self, chosen: DecisionKind) -> bool {
+        matches!(
+            (chosen, self),
+            (DecisionKind::Navigate, UserAction::Searched)
+                | (DecisionKind::Search, UserAction::Navigated)
+                | (DecisionKind::Blocked, UserAction::Navigated)
+        )
+    }
+}
+
+/// Coarse shape of an input, the only thing feedback keeps of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum InputShape {
+    /// `router`, `rust`
+    SingleWord,
+    /// `example.com`, `report.pdf`, `1.5`
+    Dotted,
+    /// `https://…`, `mailto:…`, `localhost:8080`
+    Scheme,
+    /// `rust serde json`
+    MultiWord,
+}
+
+impl InputShape {
+    pub fn of(input: &str) -> Self {
+        let input = input.trim();
+        if input.contains(char::is_whitespace) {
+            InputShape::MultiWord
+        } else if input.split_once(':').is_some_and(|(scheme, _)| {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        }) {
+            InputShape::Scheme
+        } else if input.contains('.') {
+            InputShape::Dotted
+        } else {
+            InputShape::SingleWord
+        }
+    }
+}
+
+/// How often one (shape, decision, action) combination was reported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedbackCount {
+    pub shape: InputShape,
+    pub chosen: DecisionKind,
+    pub actual: UserAction,
+    pub count: u64,
+}
+
+/// Aggregate feedback since the process started or the last reset.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedbackStats {
+    pub counts: Vec<FeedbackCount>,
+}
+
+impl FeedbackStats {
+    /// Number of reports for `chosen` decisions.
+    pub fn total(&self, chosen: DecisionKind) -> u64 {
+        self.counts.iter().filter(|c| c.chosen == chosen).map(|c| c.count).sum()
+    }
+
+    /// Share of `chosen` decisions the user overrode, `None` without reports.
+    pub fn correction_rate(&self, chosen: DecisionKind) -> Option<f64> {
+        let total = self.total(chosen);
+        let corrected: u64 = self
+            .counts
+            .iter()
+            .filter(|c| c.chosen == chosen && c.actual.corrects(chosen))
+            .map(|c| c.count)
+            .sum();
+        (total > 0).then(|| corrected as f64 / total as f64)
+    }
+}
+
+type CounterKey = (InputShape, DecisionKind, UserAction);
+
+static COUNTERS: Lazy<Mutex<BTreeMap<CounterKey, u64>>> = Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+pub(crate) fn record(input: &str, chosen: DecisionKind, actual: UserAction) {
+    let mut counters = COUNTERS.lock().unwrap_or_else(|e| e.into_inner());
+    *counters.entry((InputShape::of(input), chosen, actual)).or_default() += 1;
+}
+
+pub(crate) fn stats() -> FeedbackStats {
+    let counters = COUNTERS.lock().unwrap_or_else(|e| e.into_inner());
+    let counts = counters
+        .iter()
+        .map(|(&(shape, chosen, actual), &count)| FeedbackCount { shape, chosen, actual, count })
+        .collect();
+    FeedbackStats { counts }
+}
+
+pub(crate) fn reset() {
+    COUNTERS.lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
@@ -0,0 +1,390 @@
+//! Local file paths typed into the address bar
+//!
+//! Absolute paths become `file://` URLs. Paths relative to a home directory
+//! (`~/Documents/report.html`, `~bob/notes.txt`) or to environment variables
+//! (`%USERPROFILE%\Downloads\file.pdf`, `$XDG_DOWNLOAD_DIR/file.pdf`) are
+//! expanded against the embedder-supplied `ClassifyContext`; the process
+//! environment is never consulted, so sandboxed hosts decide what is visible.
+//!
+//! Paths are read in the style of `ClassifyContext::platform` when one is
+//! given (`C:\Users\…` for Windows, `/home/…` elsewhere) and of the build
+//! target otherwise. `./` and `../` paths resolve against `base_dir`.
+//!
+//! Windows namespace prefixes are unwrapped first: `\\?\C:\long\path` and
+//! `\\?\UNC\server\share` name ordinary files, while `\\.\` device paths are
+//! rejected unless `Policy::allow_device_paths` is set.
+
+use std::borrow::Cow;
+
+use percent_encoding::{percent_decode_str, percent_encode, AsciiSet, CONTROLS};
+use url::Url;
+
+use crate::{ClassifyContext, FileKind, Platform, Policy};
+
+/// Tells whether a local path exists, for `Policy::require_existing_file_paths`.
+///
+/// Sandboxed hosts that can't touch the filesystem from the classifier supply
+/// their own; otherwise [`FsFileChecker`] is used.
+pub trait FileChecker: Send + Sync + 'static {
+    /// `path` is expanded and absolute, in the platform's native form.
+    fn exists(&self, path: &str) -> bool;
+}
+
+/// Checks paths against the local filesystem. WASI builds have no filesystem
+/// to check, so every path is missing there.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FsFileChecker;
+
+impl FileChecker for FsFileChecker {
+    #[cfg(not(target_os = "wasi"))]
+    fn exists(&self, path: &str) -> bool {
+        std::path::Path::new(path).exists()
+    }
+
+    #[cfg(target_os = "wasi")]
+    fn exists(&self, _path: &str) -> bool {
+        false
+    }
+}
+
+/// `file://` URL for `input` when it is an absolute path, after expansion.
+#[cfg_attr(feature = "trace", tracing::instrument(level = "trace", skip(policy, ctx, checker), ret))]
+pub(crate) fn file_url(input: &str, policy: &Policy, ctx: &ClassifyContext, checker: &dyn FileChecker) -> Option<String> {
+    let path = expand_path(input, ctx)?;
+    let path = unwrap_windows_namespace(&path, policy.allow_device_paths)?;
+    let url = match ctx.platform {
+        None => Url::from_file_path(&*path).ok()?,
+        Some(Platform::Windows) => windows_path_url(&path)?,
+        Some(_) => posix_path_url(&path)?,
+    };
+    if policy.require_existing_file_paths && !checker.exists(&path) {
+        return None;
+    }
+    Some(url.to_string())
+}
+
+/// Bytes escaped in file URL path segments: what `Url::from_file_path`
+/// escapes, plus `\` which a file URL would otherwise read as a separator.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}')
+    .add(b'/')
+    .add(b'%')
+    .add(b'\\');
+
+/// Re-encodes the path of a typed or pasted `file:` URL the way a path input
+/// would be, so `My%20File.pdf` and `My File.pdf` give the same URL, stray `%`
+/// signs become `%25` and drive letters are uppercase.
+pub(crate) fn normalize_file_url(url: &str) -> Option<String> {
+    let mut u = Url::parse(url).ok()?;
+    if u.scheme() != "file" {
+        return None;
+    }
+    let segments: Vec<String> = u
+        .path_segments()?
+        .enumerate()
+        .map(|(i, segment)| {
+            // bytes, not UTF-8: file names needn't be valid UTF-8
+            let decoded: Vec<u8> = percent_decode_str(segment).collect();
+            let is_drive = i == 0 && decoded.len() == 2 && decoded[0].is_ascii_alphabetic() && decoded[1] == b':';
+            if is_drive {
+                String::from_utf8_lossy(&decoded).to_ascii_uppercase()
+            } else {
+                percent_encode(&decoded, PATH_SEGMENT).to_string()
+            }
+        })
+        .collect();
+    u.set_path(&format!("/{}", segments.join("/")));
+    Some(u.to_string())
+}
+
+/// `file://` URL for an absolute POSIX path, encoded as `Url::from_file_path`
+/// does on Unix: empty and `.` segments are dropped, `..` is kept.
+fn posix_path_url(path: &str) -> Option<Url> {
+    let rest = path.strip_prefix('/')?;
+    let mut url = String::from("file://");
+    for segment in rest.split('/').filter(|s| !s.is_empty() && *s != ".") {
+        url.push('/');
+        url.extend(percent_encode(segment.as_bytes(), PATH_SEGMENT));
+    }
+    if url.len() == "file://".len() {
+        url.push('/');
+    }
+    Url::parse(&url).ok()
+}
+
+/// `file://` URL for an absolute Windows drive (`C:\dir`) or UNC
+/// (`\\server\share\dir`) path, as `Url::from_file_path` builds it on Windows.
+fn windows_path_url(path: &str) -> Option<Url> {
+    let bytes = path.as_bytes();
+    let (mut url, rest) = if bytes.len() >= 3 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' && matches!(bytes[2], b'\\' | b'/') {
+        (format!("file:///{}", path[..2].to_ascii_uppercase()), &path[3..])
+    } else if let Some(unc) = path.strip_prefix(r"\\") {
+        let (server, rest) = unc.split_once('\\').filter(|(server, _)| !server.is_empty())?;
+        (format!("file://{}", server), rest)
+    } else {
+        return None;
+    };
+    for segment in rest.split(['\\', '/']).filter(|s| !s.is_empty() && *s != ".") {
+        url.push('/');
+        url.extend(percent_encode(segment.as_bytes(), PATH_SEGMENT));
+    }
+    Url::parse(&url).ok().filter(crate::url_host_length_valid)
+}
+
+const HTML_EXTENSIONS: &[&str] = &["htm", "html", "mht", "mhtml", "shtml", "xht", "xhtml"];
+
+const MEDIA_EXTENSIONS: &[&str] = &[
+    "aac", "avi", "avif", "bmp", "flac", "gif", "heic", "ico", "jpeg", "jpg", "m4a", "m4v", "mkv", "mov", "mp3",
+    "mp4", "oga", "ogg", "ogv", "opus", "png", "svg", "tif", "tiff", "wav", "webm", "webp", "wmv",
+];
+
+/// Programs, installers, scripts and shortcuts the OS runs when they're opened.
+const EXECUTABLE_EXTENSIONS: &[&str] = &[
+    "app", "appimage", "appx", "apk", "bat", "bin", "cmd", "com", "command", "cpl", "deb", "dmg", "exe", "hta", "jar",
+    "lnk", "msc", "msi", "msix", "pkg", "ps1", "reg", "rpm", "run", "scr", "sh", "vbe", "vbs", "wsf",
+];
+
+/// Kind and lowercase extension of the file a `file:` URL points at, if known.
+pub(crate) fn file_kind(url: &str) -> Option<(FileKind, String)> {
+    let u = Url::parse(url).ok()?;
+    if u.scheme() != "file" {
+        return None;
+    }
+    let name = u.path_segments()?.next_back()?;
+    let name = percent_decode_str(name).decode_utf8_lossy();
+    let (_, extension) = name.rsplit_once('.')?;
+    let extension = extension.to_ascii_lowercase();
+    let kind = match extension.as_str() {
+        "pdf" => FileKind::Pdf,
+        e if HTML_EXTENSIONS.contains(&e) => FileKind::Html,
+        e if MEDIA_EXTENSIONS.contains(&e) => FileKind::Media,
+        e if EXECUTABLE_EXTENSIONS.contains(&e) => FileKind::Executable,
+        _ => return None,
+    };
+    Some((kind, extension))
+}
+
+/// Rewrites Win32 namespace paths to the plain drive or UNC path they name.
+/// `None` for namespaces without a file URL equivalent (`\\.\PhysicalDrive0`,
+/// `\\?\Volume{…}\`, pipes) and for device paths unless `allow_device_paths`.
+pub(crate) fn unwrap_windows_namespace(path: &str, allow_device_paths: bool) -> Option<Cow<'_, str>> {
+    let (rest, is_device) = if let Some(rest) = path.strip_prefix(r"\\?\") {
+        (rest, false)
+    } else if let Some(rest) = path.strip_prefix(r"\\.\") {
+        (rest, true)
+    } else {
+        return Some(Cow::Borrowed(path));
+    };
+    if is_device && !allow_device_paths {
+        return None;
+    }
+
+    let bytes = rest.as_bytes();
+    if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+        // `\\?\C:\path` → `C:\path`
+        return Some(Cow::Borrowed(rest));
+    }
+    let unc = rest.get(..4).filter(|p| p.eq_ignore_ascii_case(r"UNC\"))?;
+    // `\\?\UNC\server\share` → `\\server\share`
+    Some(Cow::Owned(format!(r"\\{}", &rest[unc.len()..])))
+}
+
+/// Expands `~`, `%VAR%` and `$VAR` references and resolves `./` and `../`
+/// against the base directory. `None` when a leading `~` can't be resolved.
+pub(crate) fn expand_path<'a>(input: &'a str, ctx: &ClassifyContext) -> Option<Cow<'a, str>> {
+    let path = expand_home(input, ctx)?;
+    let path = keep_or_replace(path, |p| expand_env_vars(p, ctx));
+    let path = keep_or_replace(path, |p| expand_shell_vars(p, ctx));
+    Some(keep_or_replace(path, |p| resolve_relative(p, ctx)))
+}
+
+fn keep_or_replace<'a>(path: Cow<'a, str>, expand: impl FnOnce(&str) -> Cow<'_, str>) -> Cow<'a, str> {
+    match expand(&path) {
+        Cow::Owned(expanded) => Cow::Owned(expanded),
+        Cow::Borrowed(_) => path,
+    }
+}
+
+/// Replaces a leading `~` with the user's home directory and `~name` with
+/// `name`'s, from `ClassifyContext::user_homes`. Inputs without one pass
+/// through; an unknown home directory can't be resolved.
+fn expand_home<'a>(input: &'a str, ctx: &ClassifyContext) -> Option<Cow<'a, str>> {
+    let Some(rest) = input.strip_prefix('~') else {
+        return Some(input.into());
+    };
+    let (user, rest) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    let home = if user.is_empty() {
+        ctx.home_dir.as_deref()?
+    } else if is_user_name(user) {
+        ctx.user_homes.get(user)?
+    } else {
+        return None;
+    };
+    Some(format!("{}{}", home.trim_end_matches('/'), rest).into())
+}
+
+/// Joins `./…` and `../…` onto `ClassifyContext::base_dir`, resolving the dot
+/// segments; other paths, and relative ones without a base, pass through.
+fn resolve_relative<'a>(path: &'a str, ctx: &ClassifyContext) -> Cow<'a, str> {
+    let is_relative = ["./", "../", ".\\", "..\\"].iter().any(|prefix| path.starts_with(prefix));
+    let Some(base) = ctx.base_dir.as_deref().filter(|_| is_relative) else {
+        return Cow::Borrowed(path);
+    };
+    let separator = if base.contains('\\') { '\\' } else { '/' };
+    // `..` never climbs above `/`, the drive or the UNC share
+    let root_len = match base.strip_prefix(r"\\") {
+        Some(unc) => 2 + unc.splitn(3, '\\').take(2).map(|s| s.len() + 1).sum::<usize>() - 1,
+        None if separator == '\\' => base.find('\\').unwrap_or(base.len()),
+        None => 0,
+    };
+    let (root, dirs) = base.split_at(root_len.min(base.len()));
+    let mut segments: Vec<&str> = dirs.split(['/', '\\']).filter(|s| !s.is_empty()).collect();
+    for segment in path.split(['/', '\\']) {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            _ => segments.push(segment),
+        }
+    }
+    let mut joined = root.to_string();
+    for segment in segments {
+        joined.push(separator);
+        joined.push_str(segment);
+    }
+    if joined.is_empty() {
+        joined.push(separator);
+    }
+    Cow::Owned(joined)
+}
+
+/// POSIX portable user names: letters, digits, `.`, `_` and `-`, not starting with `-`.
+fn is_user_name(name: &str) -> bool {
+    !name.starts_with('-') && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+}
+
+/// Replaces `%NAME%` with the value from `ClassifyContext::env_vars`, matching
+/// names case-insensitively as Windows does. Unknown names stay literal, and
+/// `%20`-style escapes aren't names since those can't start with a digit.
+fn expand_env_vars<'a>(path: &'a str, ctx: &ClassifyContext) -> Cow<'a, str> {
+    if ctx.env_vars.is_empty() || !path.contains('%') {
+        return Cow::Borrowed(path);
+    }
+    let mut out = String::with_capacity(path.len());
+    let mut rest = path;
+    let mut expanded = false;
+    while let Some(start) = rest.find('%') {
+        let after = &rest[start + 1..];
+        let value = after.find('%').and_then(|end| {
+            let name = &after[..end];
+            is_var_name(name).then(|| lookup(ctx, name)).flatten().map(|v| (v, end))
+        });
+        match value {
+            Some((value, end)) => {
+                out.push_str(&rest[..start]);
+                out.push_str(value);
+                rest = &after[end + 1..];
+                expanded = true;
+            }
+            None => {
+                out.push_str(&rest[..start + 1]);
+                rest = after;
+            }
+        }
+    }
+    if !expanded {
+        return Cow::Borrowed(path);
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+fn is_var_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '(' | ')'))
+}
+
+fn lookup<'c>(ctx: &'c ClassifyContext, name: &str) -> Option<&'c str> {
+    ctx.env_vars
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Replaces `$NAME` and `${NAME}` the way a POSIX shell would: names are
+/// case-sensitive and unknown ones stay literal. `$HOME` and the XDG base
+/// directories fall back to their defaults under `ClassifyContext::home_dir`.
+fn expand_shell_vars<'a>(path: &'a str, ctx: &ClassifyContext) -> Cow<'a, str> {
+    if !path.contains('$') {
+        return Cow::Borrowed(path);
+    }
+    let mut out = String::with_capacity(path.len());
+    let mut rest = path;
+    let mut expanded = false;
+    while let Some(start) = rest.find('$') {
+        let after = &rest[start + 1..];
+        let (name, len) = match after.strip_prefix('{') {
+            Some(braced) => match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
+            },
+            None => {
+                let end = after.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(after.len());
+                (&after[..end], end)
+            }
+        };
+        let value = (len > 0 && is_shell_var_name(name)).then(|| lookup_shell_var(ctx, name)).flatten();
+        match value {
+            Some(value) => {
+                out.push_str(&rest[..start]);
+                out.push_str(&value);
+                rest = &after[len..];
+                expanded = true;
+            }
+            None => {
+                out.push_str(&rest[..start + 1]);
+                rest = after;
+            }
+        }
+    }
+    if !expanded {
+        return Cow::Borrowed(path);
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+fn is_shell_var_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_') && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Defaults from the XDG Base Directory specification, relative to the home directory.
+const XDG_DEFAULTS: &[(&str, &str)] = &[
+    ("XDG_CONFIG_HOME", ".config"),
+    ("XDG_DATA_HOME", ".local/share"),
+    ("XDG_STATE_HOME", ".local/state"),
+    ("XDG_CACHE_HOME", ".cache"),
+];
+
+fn lookup_shell_var<'c>(ctx: &'c ClassifyContext, name: &str) -> Option<Cow<'c, str>> {
+    if let Some(value) = ctx.env_vars.get(name) {
+        return Some(Cow::Borrowed(value));
+    }
+    let home = ctx.home_dir.as_deref()?;
+    if name == "HOME" {
+        return Some(Cow::Borrowed(home));
+    }
+    let (_, dir) = XDG_DEFAULTS.iter().find(|(var, _)| *var == name)?;
+    Some(Cow::Owned(format!("{}/{}", home.trim_end_matches('/'), dir)))
+}
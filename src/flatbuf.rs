@@ -0,0 +1,215 @@
+//! FlatBuffers decision output
+//!
+//! Writes a [`Decision`] as the `Decision` table of `flatbuffers/decision.fbs`,
+//! so clients read its fields in place, without JSON parsing or string copies.
+
+use flatbuffers::{Allocator, FlatBufferBuilder, TableFinishedWIPOffset, VOffsetT, WIPOffset};
+
+use crate::{Decision, IdnaError, Intent, Warning};
+
+/// `file_identifier` of the schema.
+pub const FILE_IDENTIFIER: &str = "DDGD";
+
+/// vtable slot of the field at `index` in the schema.
+const fn slot(index: VOffsetT) -> VOffsetT {
+    4 + 2 * index
+}
+
+mod decision_field {
+    use super::slot;
+    use flatbuffers::VOffsetT;
+
+    pub const KIND: VOffsetT = slot(0);
+    pub const URL: VOffsetT = slot(1);
+    pub const QUERY: VOffsetT = slot(2);
+    pub const SEARCH_ALTERNATIVE: VOffsetT = slot(3);
+    pub const UNKNOWN_SCHEME_NAVIGATION: VOffsetT = slot(4);
+    pub const DISPLAY_HOST: VOffsetT = slot(5);
+    pub const THREAT: VOffsetT = slot(6);
+    pub const REASON: VOffsetT = slot(7);
+    pub const HINT: VOffsetT = slot(8);
+    pub const IDNA_PROCESSING: VOffsetT = slot(9);
+    pub const FILE_KIND: VOffsetT = slot(10);
+    pub const TRANSFORMS: VOffsetT = slot(11);
+    pub const WARNINGS: VOffsetT = slot(12);
+    pub const DID_YOU_MEAN: VOffsetT = slot(13);
+    pub const INTENT: VOffsetT = slot(14);
+    pub const IDNA_ERROR: VOffsetT = slot(15);
+}
+
+mod warning_field {
+    use super::slot;
+    use flatbuffers::VOffsetT;
+
+    pub const KIND: VOffsetT = slot(0);
+    pub const VALUE: VOffsetT = slot(1);
+    pub const HAD_PASSWORD: VOffsetT = slot(2);
+}
+
+mod intent_field {
+    use super::slot;
+    use flatbuffers::VOffsetT;
+
+    pub const KIND: VOffsetT = slot(0);
+    pub const VALUE: VOffsetT = slot(1);
+    pub const SECONDS: VOffsetT = slot(2);
+}
+
+mod idna_error_field {
+    use super::slot;
+    use flatbuffers::VOffsetT;
+
+    pub const KIND: VOffsetT = slot(0);
+    pub const VALUE: VOffsetT = slot(1);
+}
+
+/// Schema value of an optional enum: `None` is 0, the Rust variants follow in order.
+fn optional_enum(discriminant: Option<u8>) -> u8 {
+    discriminant.map_or(0, |d| d + 1)
+}
+
+/// Adds `decision` to `fbb` and returns its offset, for embedding in a caller's
+/// own tables. Use [`finish_decision`] for a standalone buffer.
+pub fn build_decision<'fbb, A: Allocator + 'fbb>(
+    fbb: &mut FlatBufferBuilder<'fbb, A>,
+    decision: &Decision,
+) -> WIPOffset<TableFinishedWIPOffset> {
+    let mut strings = Vec::new();
+    let mut transforms = None;
+    let mut warnings = None;
+    let mut intent = None;
+    let mut idna_error = None;
+    let mut reason = 0;
+    let (mut hint, mut idna_processing, mut file_kind) = (0, 0, 0);
+    match decision {
+        Decision::Navigate { url, search_alternative, warnings: w, display_host, transforms: t, idna_processing: i, file_kind: f } => {
+            strings.push((decision_field::URL, Some(url)));
+            strings.push((decision_field::SEARCH_ALTERNATIVE, search_alternative.as_ref()));
+            strings.push((decision_field::DISPLAY_HOST, display_host.as_ref()));
+            if !t.is_empty() {
+                let t: Vec<u8> = t.iter().map(|&t| t as u8).collect();
+                transforms = Some(fbb.create_vector(&t));
+            }
+            if !w.is_empty() {
+                let w: Vec<_> = w.iter().map(|w| build_warning(fbb, w)).collect();
+                warnings = Some(fbb.create_vector(&w));
+            }
+            idna_processing = optional_enum(i.map(|i| i as u8));
+            file_kind = optional_enum(f.map(|f| f as u8));
+        }
+        Decision::Search { query, unknown_scheme_navigation, reason: r, hint: h, did_you_mean, intent: i, idna_error: e } => {
+            strings.push((decision_field::QUERY, Some(query)));
+            strings.push((decision_field::UNKNOWN_SCHEME_NAVIGATION, unknown_scheme_navigation.as_ref()));
+            strings.push((decision_field::DID_YOU_MEAN, did_you_mean.as_ref()));
+            intent = i.as_ref().map(|i| build_intent(fbb, i));
+            idna_error = e.as_ref().map(|e| build_idna_error(fbb, e));
+            reason = optional_enum(r.map(|r| r as u8));
+            hint = optional_enum(h.map(|h| h as u8));
+        }
+        Decision::Blocked { url, threat, reason: r } => {
+            strings.push((decision_field::URL, Some(url)));
+            strings.push((decision_field::THREAT, Some(threat)));
+            reason = optional_enum(r.map(|r| r as u8));
+        }
+    }
+    let strings: Vec<_> = strings
+        .into_iter()
+        .filter_map(|(field, s)| Some((field, fbb.create_string(s?))))
+        .collect();
+
+    let table = fbb.start_table();
+    for (field, s) in strings {
+        fbb.push_slot_always(field, s);
+    }
+    if let Some(transforms) = transforms {
+        fbb.push_slot_always(decision_field::TRANSFORMS, transforms);
+    }
+    if let Some(warnings) = warnings {
+        fbb.push_slot_always(decision_field::WARNINGS, warnings);
+    }
+    if let Some(intent) = intent {
+        fbb.push_slot_always(decision_field::INTENT, intent);
+    }
+    if let Some(idna_error) = idna_error {
+        fbb.push_slot_always(decision_field::IDNA_ERROR, idna_error);
+    }
+    fbb.push_slot(decision_field::KIND, decision.kind() as u8, 0);
+    fbb.push_slot(decision_field::REASON, reason, 0);
+    fbb.push_slot(decision_field::HINT, hint, 0);
+    fbb.push_slot(decision_field::IDNA_PROCESSING, idna_processing, 0);
+    fbb.push_slot(decision_field::FILE_KIND, file_kind, 0);
+    let table = fbb.end_table(table);
+    WIPOffset::new(table.value())
+}
+
+fn build_warning<'fbb, A: Allocator + 'fbb>(
+    fbb: &mut FlatBufferBuilder<'fbb, A>,
+    warning: &Warning,
+) -> WIPOffset<TableFinishedWIPOffset> {
+    let (kind, value, had_password): (u8, Option<&String>, bool) = match warning {
+        Warning::PossibleSpoof { lookalike_of } => (0, lookalike_of.as_ref(), false),
+        Warning::MixedScript { label } => (1, Some(label), false),
+        Warning::CredentialsStripped { username, had_password } => (2, Some(username), *had_password),
+        Warning::Reputation { threat } => (3, Some(threat), false),
+        Warning::ExecutableFile { extension } => (4, Some(extension), false),
+    };
+    let value = value.map(|value| fbb.create_string(value));
+
+    let table = fbb.start_table();
+    if let Some(value) = value {
+        fbb.push_slot_always(warning_field::VALUE, value);
+    }
+    fbb.push_slot(warning_field::KIND, kind, 0);
+    fbb.push_slot(warning_field::HAD_PASSWORD, had_password, false);
+    let table = fbb.end_table(table);
+    WIPOffset::new(table.value())
+}
+
+fn build_intent<'fbb, A: Allocator + 'fbb>(
+    fbb: &mut FlatBufferBuilder<'fbb, A>,
+    intent: &Intent,
+) -> WIPOffset<TableFinishedWIPOffset> {
+    let (kind, value, seconds): (u8, Option<&String>, u64) = match intent {
+        Intent::Time { place } => (0, place.as_ref(), 0),
+        Intent::Date { date } => (1, Some(date), 0),
+        Intent::Timer { seconds } => (2, None, *seconds),
+    };
+    let value = value.map(|value| fbb.create_string(value));
+
+    let table = fbb.start_table();
+    if let Some(value) = value {
+        fbb.push_slot_always(intent_field::VALUE, value);
+    }
+    fbb.push_slot(intent_field::SECONDS, seconds, 0);
+    fbb.push_slot(intent_field::KIND, kind, 0);
+    let table = fbb.end_table(table);
+    WIPOffset::new(table.value())
+}
+
+fn build_idna_error<'fbb, A: Allocator + 'fbb>(
+    fbb: &mut FlatBufferBuilder<'fbb, A>,
+    error: &IdnaError,
+) -> WIPOffset<TableFinishedWIPOffset> {
+    let (kind, value): (u8, Option<&String>) = match error {
+        IdnaError::InvalidPunycode { label } => (0, Some(label)),
+        IdnaError::DisallowedCharacter { character } => (1, Some(character)),
+        IdnaError::Invalid => (2, None),
+    };
+    let value = value.map(|value| fbb.create_string(value));
+
+    let table = fbb.start_table();
+    if let Some(value) = value {
+        fbb.push_slot_always(idna_error_field::VALUE, value);
+    }
+    fbb.push_slot(idna_error_field::KIND, kind, 0);
+    let table = fbb.end_table(table);
+    WIPOffset::new(table.value())
+}
+
+/// Resets `fbb` and writes `decision` as the root of a finished buffer, readable
+/// with `fbb.finished_data()`.
+pub fn finish_decision<'fbb, A: Allocator + 'fbb>(fbb: &mut FlatBufferBuilder<'fbb, A>, decision: &Decision) {
+    fbb.reset();
+    let root = build_decision(fbb, decision);
+    fbb.finish(root, Some(FILE_IDENTIFIER));
+}
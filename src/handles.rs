@@ -0,0 +1,202 @@
+//! FFI handle validation
+//!
+//! Handles ([`DdgUpPolicy`](crate::DdgUpPolicy), [`DdgUpResults`](crate::DdgUpResults))
+//! are opaque to C. Release builds hand out the boxed object's address and
+//! only catch NULL. Debug builds hand out generation-tagged tokens instead:
+//! a slot index and the slot's generation, resolved through a registry, so
+//! a handle used after it was destroyed, destroyed twice, or passed where
+//! another kind is expected is reported instead of being undefined behavior.
+//! Problems are logged through the callback set with
+//! [`ddg_up_set_log_callback`](crate::ddg_up_set_log_callback), or to stderr.
+
+use std::cell::Cell;
+use std::ffi::{c_void, CString};
+use std::os::raw::c_char;
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+/// The outcome of the last handle call, see
+/// [`ddg_up_last_handle_status`](crate::ddg_up_last_handle_status).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DdgUpHandleStatus {
+    Ok,
+    /// The handle was NULL
+    Null,
+    /// The handle was destroyed already (debug builds)
+    Destroyed,
+    /// Not a handle this library created, or one of another kind (debug builds)
+    Invalid,
+}
+
+/// C callback for library diagnostics: `message` is valid for the duration
+/// of the call; `user_data` is passed through.
+pub type LogCallback = unsafe extern "C" fn(message: *const c_char, user_data: *mut c_void);
+
+struct CallbackLogger {
+    callback: LogCallback,
+    user_data: *mut c_void,
+}
+
+// The registering side promises the callback and `user_data` may be used from any thread.
+unsafe impl Send for CallbackLogger {}
+unsafe impl Sync for CallbackLogger {}
+
+static LOGGER: Lazy<RwLock<Option<CallbackLogger>>> = Lazy::new(|| RwLock::new(None));
+
+thread_local! {
+    static LAST_STATUS: Cell<DdgUpHandleStatus> = const { Cell::new(DdgUpHandleStatus::Ok) };
+}
+
+pub(crate) fn install_logger(callback: Option<LogCallback>, user_data: *mut c_void) {
+    *LOGGER.write().unwrap_or_else(|e| e.into_inner()) = callback.map(|callback| CallbackLogger { callback, user_data });
+}
+
+pub(crate) fn log(message: &str) {
+    match &*LOGGER.read().unwrap_or_else(|e| e.into_inner()) {
+        Some(logger) => {
+            let message = CString::new(message).unwrap_or_default();
+            unsafe { (logger.callback)(message.as_ptr(), logger.user_data) }
+        }
+        None => eprintln!("{message}"),
+    }
+}
+
+pub(crate) fn last_status() -> DdgUpHandleStatus {
+    LAST_STATUS.get()
+}
+
+/// Records `status` as the last on this thread, logging it unless it's `Ok`.
+fn report<T>(handle: *const T, status: DdgUpHandleStatus) -> DdgUpHandleStatus {
+    LAST_STATUS.set(status);
+    let problem = match status {
+        DdgUpHandleStatus::Ok => return status,
+        DdgUpHandleStatus::Null => "is NULL",
+        DdgUpHandleStatus::Destroyed => "was destroyed already",
+        DdgUpHandleStatus::Invalid => "is not a live handle of this kind",
+    };
+    let kind = std::any::type_name::<T>().rsplit("::").next().unwrap_or_default();
+    log(&format!("url_predictor: {kind} handle {handle:p} {problem}"));
+    status
+}
+
+/// Hands `value` out as a handle.
+pub(crate) fn register<T: Send + 'static>(value: T) -> *mut T {
+    imp::register(Box::new(value))
+}
+
+/// The object behind a live handle.
+pub(crate) fn resolve<T: Send + 'static>(handle: *const T) -> Result<*mut T, DdgUpHandleStatus> {
+    let resolved = if handle.is_null() { Err(DdgUpHandleStatus::Null) } else { imp::resolve(handle) };
+    report(handle, resolved.err().unwrap_or(DdgUpHandleStatus::Ok));
+    resolved
+}
+
+/// Takes the object behind a live handle back, ending the handle. NULL is
+/// accepted and releases nothing.
+///
+/// # Safety
+/// - In release builds, `handle` must come from [`register`] and not have
+///   been released yet.
+pub(crate) unsafe fn release<T: Send + 'static>(handle: *mut T) -> DdgUpHandleStatus {
+    if handle.is_null() {
+        LAST_STATUS.set(DdgUpHandleStatus::Ok);
+        return DdgUpHandleStatus::Ok;
+    }
+    match unsafe { imp::release(handle) } {
+        Ok(value) => {
+            drop(value);
+            report(handle, DdgUpHandleStatus::Ok)
+        }
+        Err(status) => report(handle, status),
+    }
+}
+
+#[cfg(not(debug_assertions))]
+mod imp {
+    use super::DdgUpHandleStatus;
+
+    pub(super) fn register<T>(value: Box<T>) -> *mut T {
+        Box::into_raw(value)
+    }
+
+    pub(super) fn resolve<T>(handle: *const T) -> Result<*mut T, DdgUpHandleStatus> {
+        Ok(handle as *mut T)
+    }
+
+    pub(super) unsafe fn release<T>(handle: *mut T) -> Result<Box<T>, DdgUpHandleStatus> {
+        Ok(unsafe { Box::from_raw(handle) })
+    }
+}
+
+#[cfg(debug_assertions)]
+mod imp {
+    use std::any::{Any, TypeId};
+    use std::sync::Mutex;
+
+    use super::DdgUpHandleStatus;
+
+    /// Low half of a token: slot index + 1, so tokens are never NULL. High
+    /// half: the slot's generation, wrapping.
+    const INDEX_BITS: u32 = usize::BITS / 2;
+    const INDEX_MASK: usize = (1 << INDEX_BITS) - 1;
+
+    struct Slot {
+        generation: usize,
+        value: Option<Box<dyn Any + Send>>,
+    }
+
+    static SLOTS: Mutex<Vec<Slot>> = Mutex::new(Vec::new());
+
+    fn token(index: usize, generation: usize) -> usize {
+        (generation << INDEX_BITS) | (index + 1)
+    }
+
+    fn slot_of(token: usize) -> (usize, usize) {
+        ((token & INDEX_MASK).wrapping_sub(1), token >> INDEX_BITS)
+    }
+
+    pub(super) fn register<T: Send + 'static>(value: Box<T>) -> *mut T {
+        let mut slots = SLOTS.lock().unwrap_or_else(|e| e.into_inner());
+        let index = match slots.iter().position(|slot| slot.value.is_none()) {
+            Some(index) => index,
+            None => {
+                slots.push(Slot { generation: 0, value: None });
+                slots.len() - 1
+            }
+        };
+        let slot = &mut slots[index];
+        slot.value = Some(value);
+        // generations only fit the high half of the token
+        token(index, slot.generation & INDEX_MASK) as *mut T
+    }
+
+    fn check<T: 'static>(slots: &[Slot], handle: *const T) -> Result<usize, DdgUpHandleStatus> {
+        let (index, generation) = slot_of(handle as usize);
+        let slot = slots.get(index).ok_or(DdgUpHandleStatus::Invalid)?;
+        let current = slot.generation & INDEX_MASK;
+        match &slot.value {
+            Some(value) if generation == current && (**value).type_id() == TypeId::of::<T>() => Ok(index),
+            // every earlier generation of the slot was destroyed
+            _ if generation < current => Err(DdgUpHandleStatus::Destroyed),
+            _ => Err(DdgUpHandleStatus::Invalid),
+        }
+    }
+
+    pub(super) fn resolve<T: 'static>(handle: *const T) -> Result<*mut T, DdgUpHandleStatus> {
+        let mut slots = SLOTS.lock().unwrap_or_else(|e| e.into_inner());
+        let index = check(&slots, handle)?;
+        let value = slots[index].value.as_mut().and_then(|value| value.downcast_mut::<T>());
+        // boxed, so the object stays put while the registry changes
+        value.map(|value| value as *mut T).ok_or(DdgUpHandleStatus::Invalid)
+    }
+
+    pub(super) unsafe fn release<T: 'static>(handle: *mut T) -> Result<Box<T>, DdgUpHandleStatus> {
+        let mut slots = SLOTS.lock().unwrap_or_else(|e| e.into_inner());
+        let index = check(&slots, handle)?;
+        let slot = &mut slots[index];
+        slot.generation = slot.generation.wrapping_add(1);
+        slot.value.take().and_then(|value| value.downcast::<T>().ok()).ok_or(DdgUpHandleStatus::Invalid)
+    }
+}
@@ -0,0 +1,18 @@
+//! Embedder heuristics
+//!
+//! Platforms keep needing small rules of their own: keyword shortcuts
+//! (`w rust` → Wikipedia), enterprise redirects (`jira` → the intranet
+//! tracker). A [`Heuristic`] registered on a `Classifier` runs ahead of the
+//! built-in rules; the first one to return a decision wins, and the built-in
+//! rules stay the last link of the chain.
+
+use crate::{ClassifyContext, Decision};
+
+/// A classification rule consulted before the built-in ones.
+pub trait Heuristic: Send + Sync + 'static {
+    /// `input` is trimmed. Return `None` to pass to the next heuristic.
+    ///
+    /// Navigate decisions still go through the policy's post-processing
+    /// (display host, IDN and credential checks, reputation).
+    fn evaluate(&self, input: &str, ctx: &ClassifyContext) -> Option<Decision>;
+}
@@ -0,0 +1,58 @@
+//! Browsing history
+//!
+//! The suffix list can't know that `router` or `wiki.corp` are places the user
+//! actually goes. A [`HistoryProvider`] breaks the tie: single-label and
+//! unknown-suffix hosts the user has visited navigate instead of searching.
+//!
+//! Native history stores register a C callback with
+//! `ddg_up_set_history_callback`; the FFI classify functions consult it.
+
+use std::ffi::{c_void, CString};
+use std::os::raw::c_char;
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
+
+/// Consulted for host-like inputs the built-in rules would search for.
+pub trait HistoryProvider: Send + Sync + 'static {
+    /// Number of past visits to `host` (ASCII, lowercase), 0 if none.
+    fn visit_count(&self, host: &str) -> u32;
+}
+
+/// C callback: `host` is a NUL-terminated ASCII host, `user_data` is passed through.
+pub type HistoryCallback = unsafe extern "C" fn(host: *const c_char, user_data: *mut c_void) -> u32;
+
+pub(crate) struct CallbackHistoryProvider {
+    callback: HistoryCallback,
+    user_data: *mut c_void,
+}
+
+// The registering side promises the callback and `user_data` may be used from any thread.
+unsafe impl Send for CallbackHistoryProvider {}
+unsafe impl Sync for CallbackHistoryProvider {}
+
+impl CallbackHistoryProvider {
+    pub(crate) fn new(callback: HistoryCallback, user_data: *mut c_void) -> Self {
+        Self { callback, user_data }
+    }
+}
+
+impl HistoryProvider for CallbackHistoryProvider {
+    fn visit_count(&self, host: &str) -> u32 {
+        let Ok(host) = CString::new(host) else {
+            return 0;
+        };
+        unsafe { (self.callback)(host.as_ptr(), self.user_data) }
+    }
+}
+
+static FFI_PROVIDER: Lazy<RwLock<Option<Arc<dyn HistoryProvider>>>> = Lazy::new(|| RwLock::new(None));
+
+/// Replaces the provider the FFI classify functions consult; `None` removes it.
+pub(crate) fn install_ffi_provider(provider: Option<Arc<dyn HistoryProvider>>) {
+    *FFI_PROVIDER.write().unwrap_or_else(|e| e.into_inner()) = provider;
+}
+
+pub(crate) fn ffi_provider() -> Option<Arc<dyn HistoryProvider>> {
+    FFI_PROVIDER.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
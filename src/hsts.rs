@@ -0,0 +1,69 @@
+//! HSTS preload list (enabled with feature = "hsts-preload")
+//!
+//! Browsers force `https://` for preloaded hosts anyway, so schemeless
+//! navigations to them start on `https://` instead of `http://`. The list is
+//! embedded from `assets/hsts_preload.txt` and can be replaced at runtime.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+const EMBEDDED_LIST: &str = include_str!("../assets/hsts_preload.txt");
+
+static ACTIVE_LIST: Lazy<RwLock<Arc<HstsPreloadList>>> = Lazy::new(|| {
+    let list = HstsPreloadList::from_list_string(EMBEDDED_LIST).expect("failed to parse HSTS preload list");
+    RwLock::new(Arc::new(list))
+});
+
+/// Set of preloaded hosts, each optionally covering its subdomains.
+#[derive(Debug, Clone, Default)]
+pub struct HstsPreloadList {
+    /// host → include_subdomains
+    hosts: HashMap<String, bool>,
+}
+
+impl HstsPreloadList {
+    /// Build from the `assets/hsts_preload.txt` format: one host per line,
+    /// optionally followed by `include_subdomains`; `#` starts a comment.
+    pub fn from_list_string(data: &str) -> Result<Self, String> {
+        let mut hosts = HashMap::new();
+        for (n, line) in data.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let mut fields = line.split_whitespace();
+            let Some(host) = fields.next() else {
+                continue;
+            };
+            let include_subdomains = match fields.next() {
+                None => false,
+                Some("include_subdomains") => true,
+                Some(other) => return Err(format!("line {}: unexpected `{}`", n + 1, other)),
+            };
+            if fields.next().is_some() {
+                return Err(format!("line {}: too many fields", n + 1));
+            }
+            hosts.insert(host.trim_end_matches('.').to_ascii_lowercase(), include_subdomains);
+        }
+        Ok(Self { hosts })
+    }
+
+    /// Whether `ascii_host` (lowercase, punycode) must be reached over https.
+    pub fn contains(&self, ascii_host: &str) -> bool {
+        let host = ascii_host.trim_end_matches('.');
+        if self.hosts.contains_key(host) {
+            return true;
+        }
+        // walk up the parents: `a.b.example.com` → `b.example.com` → `example.com` → `com`
+        host.match_indices('.')
+            .any(|(i, _)| self.hosts.get(&host[i + 1..]).copied().unwrap_or(false))
+    }
+}
+
+/// Replaces the active list, e.g. with a fresher copy downloaded by the client.
+pub(crate) fn install(list: HstsPreloadList) {
+    *ACTIVE_LIST.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(list);
+}
+
+pub(crate) fn is_preloaded(ascii_host: &str) -> bool {
+    let list = ACTIVE_LIST.read().unwrap_or_else(|e| e.into_inner()).clone();
+    list.contains(ascii_host)
+}
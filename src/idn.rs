@@ -0,0 +1,238 @@
+//! IDN safety checks
+//!
+//! Hosts that render as Unicode can impersonate well-known domains
+//! (`аpple.com` with a Cyrillic `а`) or mix scripts within a label. The checks here only look at the final
+//! host of a Navigate decision and report warnings; what to do with them is up
+//! to the policy. Plain ASCII lookalikes of popular registrable domains
+//! (`g00gle.com`, `rnicrosoft.com`) are reported the same way.
+
+use crate::{IdnaError, Warning};
+
+/// Frequently impersonated domains, compared against lookalike hosts.
+const POPULAR_DOMAINS: &[&str] = &[
+    "adobe.com", "amazon.com", "apple.com", "bankofamerica.com", "binance.com", "bing.com",
+    "chase.com", "coinbase.com", "dropbox.com", "duckduckgo.com", "ebay.com", "facebook.com",
+    "github.com", "gmail.com", "google.com", "icloud.com", "instagram.com", "linkedin.com",
+    "live.com", "microsoft.com", "netflix.com", "office.com", "outlook.com", "paypal.com",
+    "reddit.com", "spotify.com", "steamcommunity.com", "tiktok.com", "twitch.tv", "twitter.com",
+    "wellsfargo.com", "whatsapp.com", "wikipedia.org", "yahoo.com", "youtube.com",
+];
+
+/// Returns the warnings for an ASCII (punycode) host.
+pub(crate) fn host_warnings(ascii_host: &str) -> Vec<Warning> {
+    if !has_idn_label(ascii_host) {
+        return Vec::new();
+    }
+    let (unicode_host, result) = idna::domain_to_unicode(ascii_host);
+    if result.is_err() {
+        return Vec::new();
+    }
+    let unicode_host = unicode_host.trim_end_matches('.');
+
+    let checks: &[fn(&str) -> Option<Warning>] = &[
+        mixed_script::check,
+        #[cfg(feature = "unicode-security")]
+        confusables::check,
+    ];
+    checks.iter().filter_map(|check| check(unicode_host)).collect()
+}
+
+/// Display form of an ASCII host: Unicode when it has IDN labels and none of
+/// the checks produced `warnings`, punycode otherwise.
+pub(crate) fn display_host(ascii_host: &str, warnings: &[Warning]) -> String {
+    if !warnings.is_empty() || !has_idn_label(ascii_host) {
+        return ascii_host.to_string();
+    }
+    match idna::domain_to_unicode(ascii_host) {
+        (unicode_host, Ok(())) => unicode_host,
+        (_, Err(_)) => ascii_host.to_string(),
+    }
+}
+
+/// Spoof warning when `registrable_domain` (eTLD+1) reads like a popular domain
+/// at a glance: `g00gle.com`, `rnicrosoft.com`, `paypal.corn`.
+pub(crate) fn registrable_domain_lookalike(registrable_domain: &str) -> Option<Warning> {
+    let skeleton = ascii_skeleton(registrable_domain);
+    POPULAR_DOMAINS
+        .iter()
+        .find(|domain| **domain != registrable_domain && ascii_skeleton(domain) == skeleton)
+        .map(|domain| Warning::PossibleSpoof { lookalike_of: Some(domain.to_string()) })
+}
+
+/// Folds ASCII glyphs that pass for one another: `0`/`o`, `1`/`l`, `rn`/`m`, `vv`/`w`.
+fn ascii_skeleton(domain: &str) -> String {
+    domain
+        .to_ascii_lowercase()
+        .replace("rn", "m")
+        .replace("vv", "w")
+        .chars()
+        .map(|c| match c {
+            '0' => 'o',
+            '1' => 'l',
+            c => c,
+        })
+        .collect()
+}
+
+/// Whether any label of `ascii_host` is punycode-encoded.
+pub(crate) fn has_idn_label(ascii_host: &str) -> bool {
+    ascii_host.split('.').any(|label| label.len() > 4 && label.get(..4).is_some_and(|prefix| prefix.eq_ignore_ascii_case("xn--")))
+}
+
+/// Label separators UTS-46 maps to `.`: full stop, ideographic and fullwidth forms.
+const LABEL_SEPARATORS: [char; 4] = ['.', '\u{3002}', '\u{FF0E}', '\u{FF61}'];
+
+/// Why IDNA rejects the host of host-shaped `input` (a dotted host, an `xn--`
+/// label, or a URL with a scheme), if it does. Bare words that merely contain
+/// a disallowed character are left alone: they are ordinary queries.
+pub(crate) fn host_idna_error(input: &str) -> Option<IdnaError> {
+    let host = crate::precheck::extract_host(input)?.host;
+    let host_shaped = input.contains("://") || host.contains(LABEL_SEPARATORS) || has_idn_label(host);
+    if !host_shaped || idna::domain_to_ascii(host).is_ok() {
+        return None;
+    }
+    let labels = host.split(LABEL_SEPARATORS);
+    if let Some(label) = labels.clone().find(|label| has_idn_label(label) && idna::domain_to_ascii(label).is_err()) {
+        return Some(IdnaError::InvalidPunycode { label: label.to_string() });
+    }
+    // joiners and combining marks fail on their own too, but only by context
+    let disallowed = |c: char| {
+        !matches!(c, '\u{200C}' | '\u{200D}')
+            && idna::domain_to_ascii(&c.to_string()).is_err()
+            && idna::domain_to_ascii(&format!("a{c}")).is_err()
+    };
+    Some(match labels.flat_map(str::chars).find(|&c| disallowed(c)) {
+        Some(c) => IdnaError::DisallowedCharacter { character: c.to_string() },
+        None => IdnaError::Invalid,
+    })
+}
+
+// -----------------------------------------------------------------------------
+// Mixed-script labels (Chromium IDN display rules)
+// -----------------------------------------------------------------------------
+mod mixed_script {
+    use crate::script::{script_of, Script};
+    use crate::Warning;
+
+    /// Script combinations allowed within one label, as in Chromium (ICU's
+    /// "highly restrictive" level): Latin may mix with the CJK scripts only.
+    const ALLOWED_COMBINATIONS: &[&[Script]] = &[
+        &[Script::Latin, Script::Han, Script::Hiragana, Script::Katakana],
+        &[Script::Latin, Script::Han, Script::Bopomofo],
+        &[Script::Latin, Script::Han, Script::Hangul],
+    ];
+
+    /// Letters that pass for digits, e.g. Cyrillic `з` for `3` in `1з3`.
+    const DIGIT_LOOKALIKES: &[char] = &[
+        'з', 'З', 'ӡ', 'ҙ', 'б', 'ч', 'о', 'О', 'θ', 'ο', 'Ο', 'ȝ', 'շ', 'օ', 'զ', 'ց', 'ձ', 'Ꮾ', 'Ꮞ',
+    ];
+
+    /// First code point of decimal digit blocks, to catch labels mixing numbering systems.
+    const DIGIT_BLOCKS: &[u32] = &[
+        0x30, 0x660, 0x6F0, 0x7C0, 0x966, 0x9E6, 0xA66, 0xAE6, 0xB66, 0xBE6, 0xC66, 0xCE6, 0xD66,
+        0xE50, 0xED0, 0xF20, 0x1040, 0x17E0, 0x1810,
+    ];
+
+    pub(super) fn check(unicode_host: &str) -> Option<Warning> {
+        unicode_host
+            .split('.')
+            .find(|label| is_unsafe_label(label))
+            .map(|label| Warning::MixedScript { label: label.to_string() })
+    }
+
+    fn is_unsafe_label(label: &str) -> bool {
+        if label.is_ascii() {
+            return false;
+        }
+        mixes_scripts(label) || is_digit_lookalike(label) || mixes_numbering_systems(label)
+    }
+
+    fn mixes_scripts(label: &str) -> bool {
+        let mut scripts: Vec<Script> = label.chars().map(script_of).filter(|s| *s != Script::Common).collect();
+        scripts.sort_by_key(|s| *s as u8);
+        scripts.dedup();
+        scripts.len() > 1
+            && !ALLOWED_COMBINATIONS
+                .iter()
+                .any(|allowed| scripts.iter().all(|s| allowed.contains(s)))
+    }
+
+    /// Only digits and digit lookalikes, with at least one lookalike: `1з3`
+    fn is_digit_lookalike(label: &str) -> bool {
+        label.chars().any(|c| DIGIT_LOOKALIKES.contains(&c))
+            && label.chars().all(|c| c.is_ascii_digit() || c == '-' || DIGIT_LOOKALIKES.contains(&c))
+    }
+
+    fn mixes_numbering_systems(label: &str) -> bool {
+        let mut blocks = label.chars().filter_map(|c| {
+            let cp = c as u32;
+            DIGIT_BLOCKS.iter().copied().find(|start| (*start..=*start + 9).contains(&cp))
+        });
+        let Some(first) = blocks.next() else {
+            return false;
+        };
+        blocks.any(|b| b != first)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Confusable detection (enabled with feature = "unicode-security")
+// -----------------------------------------------------------------------------
+#[cfg(feature = "unicode-security")]
+mod confusables {
+    use super::POPULAR_DOMAINS;
+    use crate::script::{script_of, Script};
+    use crate::Warning;
+    use unicode_security::skeleton;
+
+    pub(super) fn check(unicode_host: &str) -> Option<Warning> {
+        if let Some(domain) = popular_lookalike(unicode_host) {
+            return Some(Warning::PossibleSpoof { lookalike_of: Some(domain.to_string()) });
+        }
+        if has_whole_script_confusable_label(unicode_host) {
+            return Some(Warning::PossibleSpoof { lookalike_of: None });
+        }
+        None
+    }
+
+    fn skeleton_of(s: &str) -> String {
+        skeleton(s).collect()
+    }
+
+    /// `аpple.com` (Cyrillic `а`) → `apple.com`
+    fn popular_lookalike(unicode_host: &str) -> Option<&'static str> {
+        POPULAR_DOMAINS.iter().copied().find(|domain| {
+            let labels = domain.split('.').count();
+            let host_labels: Vec<&str> = unicode_host.split('.').collect();
+            if host_labels.len() < labels {
+                return false;
+            }
+            let tail = host_labels[host_labels.len() - labels..].join(".");
+            tail != *domain && skeleton_of(&tail) == skeleton_of(domain)
+        })
+    }
+
+    /// A label written entirely in one non-Latin script that is
+    /// indistinguishable from Latin, e.g. Cyrillic `сео`. Labels under a TLD of
+    /// the same script (`пример.рф`) are expected and left alone.
+    fn has_whole_script_confusable_label(unicode_host: &str) -> bool {
+        let labels: Vec<&str> = unicode_host.split('.').collect();
+        let tld_script = labels.last().and_then(|tld| single_script(tld));
+
+        labels.iter().any(|label| {
+            let Some(script) = single_script(label) else {
+                return false;
+            };
+            script != Script::Latin
+                && Some(script) != tld_script
+                && skeleton_of(label).chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+    }
+
+    /// The only non-Common script of `label`, if it uses exactly one.
+    fn single_script(label: &str) -> Option<Script> {
+        let mut scripts = label.chars().map(script_of).filter(|s| *s != Script::Common);
+        let first = scripts.next()?;
+        scripts.all(|s| s == first).then_some(first)
+    }
+}
@@ -0,0 +1,88 @@
+//! Instant-answer intents (feature `intents`)
+//!
+//! Some searches ask for something the client can answer itself: the time
+//! somewhere, a date, a countdown. Tagging the decision with the parsed intent
+//! saves clients from parsing the query again. The phrase tables are English
+//! only, like the other query heuristics.
+
+use crate::query::parse_date;
+use crate::Intent;
+
+/// Phrases asking for the current time, longest first; a place may follow
+/// after `in`.
+const TIME_PHRASES: &[&str] = &[
+    "what time is it",
+    "what's the time",
+    "whats the time",
+    "what is the time",
+    "current time",
+    "local time",
+    "time now",
+    "time",
+];
+
+/// Phrases around a timer's duration, as (prefix, suffix).
+const TIMER_PHRASES: &[(&str, &str)] = &[
+    ("set a timer for ", ""),
+    ("set timer for ", ""),
+    ("set a timer ", ""),
+    ("set timer ", ""),
+    ("timer for ", ""),
+    ("timer ", ""),
+    ("", " timer"),
+];
+
+/// The intent of search `query`; `locale` orders the day and month of dates.
+pub(crate) fn detect(query: &str, locale: Option<&str>) -> Option<Intent> {
+    let query = query.split_whitespace().collect::<Vec<_>>().join(" ");
+    if let Some((year, month, day)) = parse_date(&query, locale) {
+        return Some(Intent::Date { date: format!("{year:04}-{month:02}-{day:02}") });
+    }
+    let query = query.trim_end_matches('?').trim_end();
+    time(query).or_else(|| timer(query))
+}
+
+fn time(query: &str) -> Option<Intent> {
+    // ASCII lowercasing keeps byte offsets, so the place is sliced as typed
+    let lower = query.to_ascii_lowercase();
+    let phrase = TIME_PHRASES.iter().find(|phrase| {
+        lower.strip_prefix(*phrase).is_some_and(|rest| rest.is_empty() || rest.starts_with(' '))
+    })?;
+    let rest = &query[phrase.len()..];
+    if rest.is_empty() {
+        return Some(Intent::Time { place: None });
+    }
+    let place = lower[phrase.len()..].starts_with(" in ").then(|| &rest[4..])?;
+    Some(Intent::Time { place: Some(place.to_string()) })
+}
+
+fn timer(query: &str) -> Option<Intent> {
+    let lower = query.to_ascii_lowercase();
+    TIMER_PHRASES.iter().find_map(|(prefix, suffix)| {
+        let duration = lower.strip_prefix(prefix)?.strip_suffix(suffix)?;
+        let seconds = parse_duration(duration)?;
+        Some(Intent::Timer { seconds })
+    })
+}
+
+/// Seconds in `10 minutes`, `1h30m`, `1 hour and 30 minutes`, `90s`.
+fn parse_duration(s: &str) -> Option<u64> {
+    let mut total = 0u64;
+    let mut rest = s.trim_start();
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let amount: u64 = rest[..digits].parse().ok()?;
+        rest = rest[digits..].trim_start();
+        let letters = rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+        let unit = match &rest[..letters] {
+            "s" | "sec" | "secs" | "second" | "seconds" => 1,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 3600,
+            _ => return None,
+        };
+        total = total.checked_add(amount.checked_mul(unit)?)?;
+        rest = rest[letters..].trim_start();
+        rest = rest.strip_prefix("and ").unwrap_or(rest).trim_start();
+    }
+    (total > 0).then_some(total)
+}
@@ -0,0 +1,34 @@
+//! Decision invariants
+//!
+//! Properties every built-in decision keeps, whatever the input and policy:
+//! navigated and blocked URLs reparse with [`Url::parse`], hold no raw
+//! spaces or control characters and have hosts that fit DNS, and searches are for the trimmed input as
+//! typed. Debug builds assert them after each classification; the property
+//! tests in `lib.rs` check them, and that navigated URLs navigate to
+//! themselves, on generated inputs.
+
+use url::Url;
+
+use crate::Decision;
+
+/// Checks `decision`, made for trimmed input `original`; the error says
+/// which invariant it breaks.
+pub(crate) fn check(original: &str, decision: &Decision) -> Result<(), String> {
+    match decision {
+        Decision::Navigate { url, .. } | Decision::Blocked { url, .. } => {
+            if let Some(c) = url.chars().find(|c| *c == ' ' || c.is_control()) {
+                return Err(format!("URL {url:?} contains {c:?}"));
+            }
+            let u = Url::parse(url).map_err(|e| format!("URL {url:?} does not parse: {e}"))?;
+            if !crate::url_host_length_valid(&u) {
+                return Err(format!("URL {url:?} has an oversized host"));
+            }
+        }
+        Decision::Search { query, .. } => {
+            if query != original {
+                return Err(format!("query {query:?} is not the input"));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! Custom keyword shortcuts
+//!
+//! `Policy::keywords` maps a keyword to a URL template, so `gh serde` with
+//! `gh` → `https://github.com/search?q={query}` navigates to GitHub's search.
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::collections::BTreeMap;
+use url::Url;
+
+/// Placeholder for the query in keyword URL templates.
+pub(crate) const QUERY_PLACEHOLDER: &str = "{query}";
+
+/// Escapes everything but unreserved characters, so a substituted query can't
+/// break out of its parameter (`&`, `#`, `+`, ...).
+pub(crate) const QUERY_COMPONENT: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
+/// Checks a keyword and its template: the keyword is a single word, and the
+/// template an http(s) URL with a `{query}` placeholder.
+pub(crate) fn validate(keyword: &str, template: &str) -> Result<(), String> {
+    if keyword.is_empty() || keyword.contains(char::is_whitespace) {
+        return Err(format!("keyword `{}` must be a single word", keyword));
+    }
+    validate_template(template).map_err(|e| format!("template for `{}` {}", keyword, e))
+}
+
+/// Checks that `template` is an http(s) URL with a `{query}` placeholder.
+pub(crate) fn validate_template(template: &str) -> Result<(), String> {
+    if !template.contains(QUERY_PLACEHOLDER) {
+        return Err(format!("has no {} placeholder", QUERY_PLACEHOLDER));
+    }
+    let url = Url::parse(&template.replace(QUERY_PLACEHOLDER, "q")).map_err(|e| format!("is not a URL: {}", e))?;
+    if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+        return Err("must be an http(s) URL".to_string());
+    }
+    Ok(())
+}
+
+/// `template` with `query` percent-encoded in place of `{query}`.
+pub(crate) fn fill_template(template: &str, query: &str) -> Option<String> {
+    let query = utf8_percent_encode(query, QUERY_COMPONENT).to_string();
+    Url::parse(&template.replace(QUERY_PLACEHOLDER, &query)).ok().map(|url| url.to_string())
+}
+
+/// Expanded URL for `keyword query…` inputs. Keywords match case-insensitively;
+/// a keyword without a query, or with an invalid template, doesn't match.
+pub(crate) fn expand(input: &str, keywords: &BTreeMap<String, String>) -> Option<String> {
+    let (keyword, query) = input.split_once(char::is_whitespace)?;
+    let query = query.trim();
+    if query.is_empty() {
+        return None;
+    }
+    let (keyword, template) = keywords.iter().find(|(k, _)| k.to_lowercase() == keyword.to_lowercase())?;
+    validate(keyword, template).ok()?;
+    fill_template(template, query)
+}
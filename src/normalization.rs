@@ -0,0 +1,140 @@
+//! Canonical form of navigated URLs (`Policy::normalization`)
+//!
+//! `Url` lowercases hosts and drops default ports itself, so keeping either as
+//! typed splices the typed host or port back into the serialized URL. Only
+//! ASCII case differences are restored: a host that was mapped (IDNA, search
+//! domains) stays as classified. Tracking parameters are removed from the raw
+//! query, so the parameters kept stay encoded as they were.
+
+use std::collections::BTreeSet;
+
+use url::{Position, Url};
+
+use crate::{Normalization, QueryEncoding, SuffixDb};
+
+/// Applies `options` to `url`, typed as `original`; true when tracking
+/// parameters were removed.
+pub(crate) fn apply(url: &mut String, original: &str, options: &Normalization, db: &dyn SuffixDb) -> bool {
+    let Normalization {
+        lowercase_host,
+        strip_default_port,
+        collapse_slashes,
+        bare_host_slash,
+        keep_trailing_slash,
+        remove_fragment,
+        strip_tracking_params,
+        strip_www,
+        ..
+    } = *options;
+    let slashes_as_parsed = bare_host_slash && keep_trailing_slash && !collapse_slashes;
+    if lowercase_host && strip_default_port && slashes_as_parsed && !remove_fragment && !strip_tracking_params && !strip_www {
+        return false;
+    }
+    let Ok(mut u) = Url::parse(url) else {
+        return false;
+    };
+    if remove_fragment {
+        u.set_fragment(None);
+    }
+    if collapse_slashes && !u.cannot_be_a_base() {
+        let mut path = String::with_capacity(u.path().len());
+        for c in u.path().chars() {
+            if !(c == '/' && path.ends_with('/')) {
+                path.push(c);
+            }
+        }
+        u.set_path(&path);
+    }
+    if !keep_trailing_slash && !u.cannot_be_a_base() && u.path().len() > 1 && u.path().ends_with('/') {
+        let path = u.path().trim_end_matches('/').to_string();
+        u.set_path(&path);
+    }
+    let stripped = strip_tracking_params && strip_params(&mut u, &options.tracking_params);
+    if strip_www {
+        let bare = u.host_str().and_then(|host| host.strip_prefix("www.")).map(str::to_string);
+        // `www.co.uk` has nothing to strip the label from
+        let is_suffix = |bare: &str| db.matching_rule(bare).is_some_and(|suffix| suffix.rule == bare);
+        if let Some(bare) = bare.filter(|bare| {
+            let bare = bare.trim_end_matches('.');
+            db.registrable_domain(bare).is_some() && !is_suffix(bare)
+        }) {
+            let _ = u.set_host(Some(&bare));
+        }
+    }
+    // `Url` always serializes an empty path as `/`, so it goes last
+    let drop_slash = !bare_host_slash
+        && u.scheme() != "file"
+        && u.host_str().is_some()
+        && u.path() == "/"
+        && u.query().is_none()
+        && u.fragment().is_none();
+
+    let (Some(typed), Some(host)) = (crate::extract_host(original), u.host_str()) else {
+        *url = u.to_string();
+        if drop_slash {
+            url.pop();
+        }
+        return stripped;
+    };
+    let host = if !lowercase_host && typed.host.eq_ignore_ascii_case(host) { typed.host } else { host };
+    // `Url` dropped the port, so the typed one is the scheme's default
+    let typed_port = typed.port.and_then(|port| port.parse::<u16>().ok());
+    let port = match (u.port(), u.port_or_known_default()) {
+        (None, Some(default)) if !strip_default_port && typed_port == Some(default) => format!(":{default}"),
+        _ => String::new(),
+    };
+    *url = format!("{}{host}{port}{}", &u[..Position::BeforeHost], &u[Position::AfterHost..]);
+    if drop_slash {
+        url.pop();
+    }
+    stripped
+}
+
+/// Removes the query parameters named in `params`, leaving the others as
+/// they were encoded; true if any were removed.
+fn strip_params(u: &mut Url, params: &BTreeSet<String>) -> bool {
+    let Some(query) = u.query() else {
+        return false;
+    };
+    let is_tracking = |name: &str| {
+        params.iter().any(|param| match param.strip_suffix('*') {
+            Some(prefix) => name.get(..prefix.len()).is_some_and(|start| start.eq_ignore_ascii_case(prefix)),
+            None => name.eq_ignore_ascii_case(param),
+        })
+    };
+    let kept: Vec<&str> =
+        query.split('&').filter(|pair| !is_tracking(pair.split_once('=').map_or(*pair, |(name, _)| name))).collect();
+    if kept.len() == query.split('&').count() {
+        return false;
+    }
+    let kept = kept.join("&");
+    u.set_query((!kept.is_empty()).then_some(kept.as_str()));
+    true
+}
+
+/// Re-encodes the query of `url` for `encoding`; spaces are already `%20`.
+pub(crate) fn encode_query(url: &mut String, encoding: QueryEncoding) {
+    if encoding == QueryEncoding::Preserve || !url.contains('?') {
+        return;
+    }
+    let Ok(mut u) = Url::parse(url) else {
+        return;
+    };
+    let Some(query) = u.query() else {
+        return;
+    };
+    let mut encoded = String::with_capacity(query.len());
+    for c in query.chars() {
+        match (c, encoding) {
+            ('+', _) => encoded.push_str("%20"),
+            ('[' | ']' | '{' | '}' | '|' | '\\' | '^' | '`', QueryEncoding::Strict) => {
+                encoded.push_str(&format!("%{:02X}", c as u32));
+            }
+            _ => encoded.push(c),
+        }
+    }
+    if encoded != query {
+        u.set_query(Some(&encoded));
+        *url = u.to_string();
+    }
+}
@@ -0,0 +1,78 @@
+//! Browser playground exports (feature `playground`)
+//!
+//! A small set of wasm-bindgen functions for the web playground, where
+//! designers and support reproduce user reports without building the apps.
+//! Everything crosses the boundary as JSON strings. The playground depends on
+//! these names and shapes, so they only change additively.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+use crate::{DecisionEnvelope, Platform, Policy};
+
+/// Classifies `input`, returning `{"v":2,"decision":…}`. `policy_json` holds
+/// `Policy` fields to change from `Policy::default()`, `context_json` a
+/// `ClassifyContext`; either may be empty.
+#[wasm_bindgen(js_name = classify)]
+pub fn classify_js(input: &str, policy_json: &str, context_json: &str) -> Result<String, JsError> {
+    classify(input, policy_json, context_json).map_err(|e| JsError::new(&e))
+}
+
+/// Like [`classify_js`], returning the whole `Explanation`: the rules tried,
+/// the one that decided, the decision and the PSL rule behind the host.
+#[wasm_bindgen(js_name = explain)]
+pub fn explain_js(input: &str, policy_json: &str, context_json: &str) -> Result<String, JsError> {
+    explain(input, policy_json, context_json).map_err(|e| JsError::new(&e))
+}
+
+/// The complete policy `platform`'s browser ships (`Windows`, `MacOs`,
+/// `Linux`, `Android`, `Ios`) as JSON, or the portable one for an empty string.
+#[wasm_bindgen(js_name = defaultPolicy)]
+pub fn default_policy_js(platform: &str) -> Result<String, JsError> {
+    default_policy(platform).map_err(|e| JsError::new(&e))
+}
+
+pub(crate) fn classify(input: &str, policy_json: &str, context_json: &str) -> Result<String, String> {
+    let decision = crate::classify_with_context(input, &policy(policy_json)?, &parse_or_default(context_json)?);
+    serde_json::to_string(&DecisionEnvelope::new(decision)).map_err(|e| e.to_string())
+}
+
+pub(crate) fn explain(input: &str, policy_json: &str, context_json: &str) -> Result<String, String> {
+    let explanation = crate::explain(input, &policy(policy_json)?, &parse_or_default(context_json)?);
+    serde_json::to_string(&explanation).map_err(|e| e.to_string())
+}
+
+pub(crate) fn default_policy(platform: &str) -> Result<String, String> {
+    let policy = if platform.is_empty() {
+        Policy::portable()
+    } else {
+        let platform: Platform =
+            serde_json::from_value(Value::String(platform.to_string())).map_err(|_| format!("unknown platform {platform}"))?;
+        Policy::shipped(platform)
+    };
+    serde_json::to_string(&policy).map_err(|e| e.to_string())
+}
+
+/// `Policy::default()` with the fields of `json` merged over it.
+fn policy(json: &str) -> Result<Policy, String> {
+    let overrides = match parse_or_default(json)? {
+        Value::Object(overrides) => overrides,
+        Value::Null => Default::default(),
+        _ => return Err("policy: expected a JSON object".into()),
+    };
+    let mut policy = serde_json::to_value(Policy::default()).map_err(|e| e.to_string())?;
+    if let Value::Object(policy) = &mut policy {
+        policy.extend(overrides);
+    }
+    let policy: Policy = serde_json::from_value(policy).map_err(|e| format!("policy: {e}"))?;
+    policy.validate_keywords()?;
+    Ok(policy)
+}
+
+fn parse_or_default<T: DeserializeOwned + Default>(json: &str) -> Result<T, String> {
+    if json.trim().is_empty() {
+        return Ok(T::default());
+    }
+    serde_json::from_str(json).map_err(|e| e.to_string())
+}
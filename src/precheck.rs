@@ -0,0 +1,152 @@
+//! Keystroke-time checks
+//!
+//! [`looks_navigable`](crate::looks_navigable) guesses whether an input will
+//! navigate from its shape alone: a scheme the policy allows, a path, or a
+//! host with a plausible TLD, port or policy-approved intranet name. No IDNA
+//! mapping, URL parsing or suffix lookup runs, so it is cheap enough for every
+//! keystroke, at the price of occasionally disagreeing with `classify`.
+//! [`extract_host`](crate::extract_host) finds the host to highlight the same
+//! way, borrowing from the input.
+
+use std::ops::Range;
+
+use crate::{is_valid_scheme, FragmentHandling, PlatformParity, Policy, Rule};
+
+/// The authority of a host-like input, as slices of the input: not
+/// lowercased, IDNA-mapped or otherwise normalized. See [`extract_host`](crate::extract_host).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostRef<'a> {
+    /// `user@example.com:8080`
+    pub authority: &'a str,
+    /// `example.com`, or `[::1]` with its brackets
+    pub host: &'a str,
+    /// `8080`; `None` when there is no port or it is still empty (`example.com:`)
+    pub port: Option<&'a str>,
+    /// Where `host` is in the input, in bytes
+    pub host_range: Range<usize>,
+}
+
+pub(crate) fn extract_host(input: &str) -> Option<HostRef<'_>> {
+    let trimmed = input.trim();
+    let rest = match trimmed.split_once("://") {
+        Some((scheme, rest)) if is_valid_scheme(scheme) => rest,
+        _ => trimmed.strip_prefix("//").unwrap_or(trimmed),
+    };
+    // `rest` ends where `input` does, less trailing whitespace
+    let offset = input.trim_end().len() - rest.len();
+    let host = split_authority(rest)?;
+    Some(HostRef { host_range: host.host_range.start + offset..host.host_range.end + offset, ..host })
+}
+
+/// The authority at the start of schemeless `input`, if it can be one.
+pub(crate) fn split_authority(input: &str) -> Option<HostRef<'_>> {
+    let authority = &input[..input.find(['/', '?', '#']).unwrap_or(input.len())];
+    if authority.contains(char::is_whitespace) {
+        return None;
+    }
+    let host_start = authority.rfind('@').map_or(0, |at| at + 1);
+    let host_port = &authority[host_start..];
+    let (host, port) = if host_port.starts_with('[') {
+        let (host, after) = host_port.split_at(host_port.find(']')? + 1);
+        match after {
+            "" => (host, None),
+            _ => (host, Some(after.strip_prefix(':')?)),
+        }
+    } else {
+        match host_port.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_port, None),
+        }
+    };
+    if port.is_some_and(|port| !port.bytes().all(|b| b.is_ascii_digit())) {
+        return None;
+    }
+    let host_chars = |c: char| !c.is_ascii() || c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '%');
+    let valid = if host.starts_with('[') { host.len() > 2 } else { !host.is_empty() && host.chars().all(host_chars) };
+    if !valid {
+        return None;
+    }
+    Some(HostRef { authority, host, port: port.filter(|port| !port.is_empty()), host_range: host_start..host_start + host.len() })
+}
+
+pub(crate) fn looks_navigable(input: &str, policy: &Policy) -> bool {
+    let input = input.trim();
+    if input.is_empty() {
+        return false;
+    }
+    let stripped = crate::without_tabs_and_newlines(input);
+    let input = stripped.as_deref().unwrap_or(input);
+    if let Some((keyword, _)) = input.split_once(char::is_whitespace) {
+        if policy.keywords.contains_key(keyword) {
+            return true;
+        }
+    }
+    if policy.allow_file_paths && looks_like_path(input) {
+        return true;
+    }
+    // `//wiki` follows the intranet policy like `wiki`
+    if let Some(rest) = input.strip_prefix("//") {
+        return !rest.contains(char::is_whitespace) && looks_like_host(rest, policy);
+    }
+    // `example.com:8080` is a host with a port, not a scheme
+    if let Some((scheme, rest)) = input.split_once(':') {
+        if is_valid_scheme(scheme) && policy.allows_scheme(scheme) {
+            return !rest.is_empty();
+        }
+    }
+    if input.contains(char::is_whitespace) {
+        return false;
+    }
+    if policy.rules.is_enabled(Rule::DigitsOnly) && !policy.navigate_digits_only && crate::query::is_digits_only(input) {
+        return false;
+    }
+    looks_like_host(input, policy)
+}
+
+/// `/etc/hosts`, `~/notes.md`, `C:\temp`, `\\server\share`.
+fn looks_like_path(input: &str) -> bool {
+    let bytes = input.as_bytes();
+    let drive = bytes.len() > 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' && matches!(bytes[2], b'\\' | b'/');
+    drive || input.starts_with(['/', '~', '%', '$']) || input.starts_with("\\\\")
+}
+
+fn looks_like_host(input: &str, policy: &Policy) -> bool {
+    let Some(HostRef { authority, host, port, .. }) = split_authority(input) else {
+        return false;
+    };
+    if host.starts_with('[') {
+        return true;
+    }
+    let host = host.strip_suffix('.').unwrap_or(host);
+    let labels_valid = host.split('.').all(|label| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || !c.is_ascii())
+    });
+    if host.is_empty() || !labels_valid || authority.ends_with(':') {
+        return false;
+    }
+    let (has_port, rest) = (port.is_some(), &input[authority.len()..]);
+    let has_path = rest.len() > 1 || rest == "/";
+    let Some((_, tld)) = host.rsplit_once('.') else {
+        return host.eq_ignore_ascii_case("localhost")
+            || has_port
+            || policy.allow_intranet_single_label
+            || ((policy.navigate_single_label_with_path || policy.parity == PlatformParity::Chromium) && has_path)
+            || (policy.fragment_handling == FragmentHandling::NavigateSingleLabel && rest.len() > 1 && rest.starts_with('#'));
+    };
+    if host.split('.').all(|label| label.bytes().all(|b| b.is_ascii_digit())) {
+        return host.split('.').count() == 4;
+    }
+    let www_single_label = host.get(..4).is_some_and(|www| www.eq_ignore_ascii_case("www.")) && !host[4..].contains('.');
+    if policy.search_www_single_label && www_single_label && !has_port && !has_path {
+        return false;
+    }
+    if policy.allow_intranet_multi_label {
+        return true;
+    }
+    let tld_like = tld.len() >= 2 && (tld.chars().all(|c| c.is_ascii_alphabetic() || !c.is_ascii()) || tld.starts_with("xn--"));
+    // `package.json`: a file name, not a host
+    tld_like && (has_port || has_path || !policy.file_extension_labels.contains(&tld.to_ascii_lowercase()))
+}
@@ -0,0 +1,337 @@
+//! Protobuf types for `Policy` and `Decision`
+//!
+//! Generated by prost from `proto/url_predictor.proto` (regenerate with
+//! `scripts/update_proto.sh`), for clients whose IPC layers already speak
+//! protobuf. Unset policy fields and `UNSPECIFIED` enum values take their
+//! `Policy::default()` value; unset decision fields mean `None`.
+
+#[allow(clippy::all)]
+mod generated {
+    include!("proto/ddg.url_predictor.v1.rs");
+}
+
+pub use generated::*;
+
+use std::collections::BTreeSet;
+
+/// `From` the crate's enum, and back from the wire value (`None` for
+/// `UNSPECIFIED` or values this build doesn't know).
+macro_rules! enum_conversions {
+    ($name:ident { $($variant:ident),* $(,)? }) => {
+        impl From<crate::$name> for $name {
+            fn from(value: crate::$name) -> Self {
+                match value {
+                    $(crate::$name::$variant => $name::$variant,)*
+                }
+            }
+        }
+
+        impl $name {
+            fn decode_value(value: i32) -> Option<crate::$name> {
+                match $name::try_from(value).ok()? {
+                    $($name::$variant => Some(crate::$name::$variant),)*
+                    $name::Unspecified => None,
+                }
+            }
+        }
+    };
+}
+
+enum_conversions!(EmailHandling { Search, Navigate, Mailto });
+enum_conversions!(PhoneHandling { Search, Dial });
+enum_conversions!(SpoofAction { Allow, Warn, Search });
+enum_conversions!(UserinfoHandling { Keep, StripAndWarn, Search });
+enum_conversions!(FragmentHandling { Keep, Strip, NavigateSingleLabel });
+enum_conversions!(QueryEncoding { Preserve, ReencodeSpaces, Strict });
+enum_conversions!(DefaultScheme { Http, Https });
+enum_conversions!(DataUrlAction { Search, Block });
+enum_conversions!(IdnaProcessing { Nontransitional, Transitional });
+enum_conversions!(ShellSchemeAction { Search, Block });
+enum_conversions!(InvalidUtf8Handling { Replace, Search });
+enum_conversions!(PlatformParity { None, MacOs, Windows, Chromium, Safari });
+enum_conversions!(Rule {
+    Bang, Keyword, AbsoluteUrl, CjkQuery, SchemeRelative, FilePath, CalculationOrConversion, Whitespace,
+    EmailAddress, HostLike, WwwPrefix, VisitedHost, DigitsOnly,
+});
+enum_conversions!(Transform { HstsUpgrade, Bang, Keyword, TopHit, TrackingParamsStripped, TabsAndNewlinesStripped });
+enum_conversions!(FileKind { Html, Pdf, Media, Executable });
+enum_conversions!(QueryHint { Navigational, Informational });
+enum_conversions!(ReasonCode {
+    Calculation, Conversion, EmailAddress, PossibleSpoof, MixedScript, Credentials, Reputation, DataUrlTooLong,
+    DataUrlMimeType, DataUrlScript, ShellScheme, ExecutableFile, InvalidEncoding, PhoneNumber,
+    Number, Date, IdnaError, DigitsOnly,
+});
+
+/// Wire value of an optional enum, `UNSPECIFIED` for `None`.
+fn encode_optional<T, P: From<T> + Into<i32>>(value: Option<T>) -> i32 {
+    value.map_or(0, |value| P::from(value).into())
+}
+
+fn set<T>(field: &mut T, value: Option<T>) {
+    if let Some(value) = value {
+        *field = value;
+    }
+}
+
+impl From<&BTreeSet<String>> for StringSet {
+    fn from(values: &BTreeSet<String>) -> Self {
+        StringSet { values: values.iter().cloned().collect() }
+    }
+}
+
+impl From<StringSet> for BTreeSet<String> {
+    fn from(set: StringSet) -> Self {
+        set.values.into_iter().collect()
+    }
+}
+
+impl From<Policy> for crate::Policy {
+    fn from(p: Policy) -> Self {
+        let mut policy = crate::Policy::default();
+        set(&mut policy.allow_intranet_multi_label, p.allow_intranet_multi_label);
+        set(&mut policy.allow_intranet_single_label, p.allow_intranet_single_label);
+        set(&mut policy.allow_private_suffix, p.allow_private_suffix);
+        set(&mut policy.allowed_schemes, p.allowed_schemes.map(Into::into));
+        set(&mut policy.allow_file_paths, p.allow_file_paths);
+        set(&mut policy.allow_device_paths, p.allow_device_paths);
+        set(&mut policy.require_existing_file_paths, p.require_existing_file_paths);
+        set(&mut policy.file_extension_labels, p.file_extension_labels.map(Into::into));
+        set(&mut policy.extract_search_queries, p.extract_search_queries);
+        set(&mut policy.email_handling, EmailHandling::decode_value(p.email_handling));
+        set(&mut policy.phone_handling, PhoneHandling::decode_value(p.phone_handling));
+        set(&mut policy.spoof_action, SpoofAction::decode_value(p.spoof_action));
+        set(&mut policy.mixed_script_action, SpoofAction::decode_value(p.mixed_script_action));
+        set(&mut policy.userinfo_handling, UserinfoHandling::decode_value(p.userinfo_handling));
+        set(&mut policy.fragment_handling, FragmentHandling::decode_value(p.fragment_handling));
+        set(&mut policy.query_encoding, QueryEncoding::decode_value(p.query_encoding));
+        set(&mut policy.search_www_single_label, p.search_www_single_label);
+        set(&mut policy.navigate_digits_only, p.navigate_digits_only);
+        set(&mut policy.navigate_single_label_with_path, p.navigate_single_label_with_path);
+        set(&mut policy.default_scheme, DefaultScheme::decode_value(p.default_scheme));
+        if let Some(data_urls) = p.data_urls {
+            set(&mut policy.data_urls.max_length, data_urls.max_length.map(|n| usize::try_from(n).unwrap_or(usize::MAX)));
+            set(&mut policy.data_urls.allowed_mime_types, data_urls.allowed_mime_types.map(Into::into));
+            set(&mut policy.data_urls.script_action, DataUrlAction::decode_value(data_urls.script_action));
+        }
+        set(&mut policy.idna_processing, IdnaProcessing::decode_value(p.idna_processing));
+        set(&mut policy.executable_file_action, SpoofAction::decode_value(p.executable_file_action));
+        set(&mut policy.shell_scheme_action, ShellSchemeAction::decode_value(p.shell_scheme_action));
+        set(&mut policy.invalid_utf8, InvalidUtf8Handling::decode_value(p.invalid_utf8));
+        set(&mut policy.parity, PlatformParity::decode_value(p.parity));
+        policy.keywords = p.keywords;
+        if let Some(rules) = p.rules {
+            policy.rules.order = rules.order.into_iter().filter_map(Rule::decode_value).collect();
+            policy.rules.disabled = rules.disabled.into_iter().filter_map(Rule::decode_value).collect();
+        }
+        set(&mut policy.max_suggestions, p.max_suggestions.map(|n| n as usize));
+        if let Some(normalization) = p.normalization {
+            set(&mut policy.normalization.lowercase_host, normalization.lowercase_host);
+            set(&mut policy.normalization.strip_default_port, normalization.strip_default_port);
+            set(&mut policy.normalization.collapse_slashes, normalization.collapse_slashes);
+            set(&mut policy.normalization.bare_host_slash, normalization.bare_host_slash);
+            set(&mut policy.normalization.keep_trailing_slash, normalization.keep_trailing_slash);
+            set(&mut policy.normalization.strip_www, normalization.strip_www);
+            set(&mut policy.normalization.remove_fragment, normalization.remove_fragment);
+            set(&mut policy.normalization.strip_tracking_params, normalization.strip_tracking_params);
+            set(&mut policy.normalization.tracking_params, normalization.tracking_params.map(Into::into));
+        }
+        policy
+    }
+}
+
+impl From<&crate::Policy> for Policy {
+    fn from(p: &crate::Policy) -> Self {
+        Policy {
+            allow_intranet_multi_label: Some(p.allow_intranet_multi_label),
+            allow_intranet_single_label: Some(p.allow_intranet_single_label),
+            allow_private_suffix: Some(p.allow_private_suffix),
+            allowed_schemes: Some((&p.allowed_schemes).into()),
+            allow_file_paths: Some(p.allow_file_paths),
+            allow_device_paths: Some(p.allow_device_paths),
+            require_existing_file_paths: Some(p.require_existing_file_paths),
+            file_extension_labels: Some((&p.file_extension_labels).into()),
+            extract_search_queries: Some(p.extract_search_queries),
+            email_handling: EmailHandling::from(p.email_handling).into(),
+            phone_handling: PhoneHandling::from(p.phone_handling).into(),
+            spoof_action: SpoofAction::from(p.spoof_action).into(),
+            mixed_script_action: SpoofAction::from(p.mixed_script_action).into(),
+            userinfo_handling: UserinfoHandling::from(p.userinfo_handling).into(),
+            fragment_handling: FragmentHandling::from(p.fragment_handling).into(),
+            query_encoding: QueryEncoding::from(p.query_encoding).into(),
+            search_www_single_label: Some(p.search_www_single_label),
+            navigate_digits_only: Some(p.navigate_digits_only),
+            navigate_single_label_with_path: Some(p.navigate_single_label_with_path),
+            default_scheme: DefaultScheme::from(p.default_scheme).into(),
+            data_urls: Some(DataUrlPolicy {
+                max_length: Some(p.data_urls.max_length as u64),
+                allowed_mime_types: Some((&p.data_urls.allowed_mime_types).into()),
+                script_action: DataUrlAction::from(p.data_urls.script_action).into(),
+            }),
+            idna_processing: IdnaProcessing::from(p.idna_processing).into(),
+            executable_file_action: SpoofAction::from(p.executable_file_action).into(),
+            shell_scheme_action: ShellSchemeAction::from(p.shell_scheme_action).into(),
+            invalid_utf8: InvalidUtf8Handling::from(p.invalid_utf8).into(),
+            parity: PlatformParity::from(p.parity).into(),
+            keywords: p.keywords.clone(),
+            rules: Some(Rules {
+                order: p.rules.order.iter().map(|&rule| Rule::from(rule).into()).collect(),
+                disabled: p.rules.disabled.iter().map(|&rule| Rule::from(rule).into()).collect(),
+            }),
+            max_suggestions: Some(u32::try_from(p.max_suggestions).unwrap_or(u32::MAX)),
+            normalization: Some(Normalization {
+                lowercase_host: Some(p.normalization.lowercase_host),
+                strip_default_port: Some(p.normalization.strip_default_port),
+                collapse_slashes: Some(p.normalization.collapse_slashes),
+                bare_host_slash: Some(p.normalization.bare_host_slash),
+                keep_trailing_slash: Some(p.normalization.keep_trailing_slash),
+                strip_www: Some(p.normalization.strip_www),
+                remove_fragment: Some(p.normalization.remove_fragment),
+                strip_tracking_params: Some(p.normalization.strip_tracking_params),
+                tracking_params: Some((&p.normalization.tracking_params).into()),
+            }),
+        }
+    }
+}
+
+impl From<&crate::Warning> for Warning {
+    fn from(warning: &crate::Warning) -> Self {
+        let kind = match warning.clone() {
+            crate::Warning::PossibleSpoof { lookalike_of } => warning::Kind::PossibleSpoof(warning::PossibleSpoof { lookalike_of }),
+            crate::Warning::MixedScript { label } => warning::Kind::MixedScript(warning::MixedScript { label }),
+            crate::Warning::CredentialsStripped { username, had_password } => {
+                warning::Kind::CredentialsStripped(warning::CredentialsStripped { username, had_password })
+            }
+            crate::Warning::Reputation { threat } => warning::Kind::Reputation(warning::Reputation { threat }),
+            crate::Warning::ExecutableFile { extension } => warning::Kind::ExecutableFile(warning::ExecutableFile { extension }),
+        };
+        Warning { kind: Some(kind) }
+    }
+}
+
+impl From<&crate::Decision> for Decision {
+    fn from(decision: &crate::Decision) -> Self {
+        let kind = match decision.clone() {
+            crate::Decision::Navigate {
+                url,
+                search_alternative,
+                warnings,
+                display_host,
+                transforms,
+                idna_processing,
+                file_kind,
+            } => decision::Kind::Navigate(Navigate {
+                url,
+                search_alternative,
+                warnings: warnings.iter().map(Warning::from).collect(),
+                display_host,
+                transforms: transforms.into_iter().map(|t| Transform::from(t).into()).collect(),
+                idna_processing: encode_optional::<_, IdnaProcessing>(idna_processing),
+                file_kind: encode_optional::<_, FileKind>(file_kind),
+            }),
+            crate::Decision::Search { query, unknown_scheme_navigation, reason, hint, did_you_mean, intent, idna_error } => {
+                decision::Kind::Search(Search {
+                    query,
+                    unknown_scheme_navigation,
+                    reason: encode_optional::<_, ReasonCode>(reason),
+                    hint: encode_optional::<_, QueryHint>(hint),
+                    did_you_mean,
+                    intent: intent.map(Intent::from),
+                    idna_error: idna_error.map(IdnaError::from),
+                })
+            }
+            crate::Decision::Blocked { url, threat, reason } => {
+                decision::Kind::Blocked(Blocked { url, threat, reason: encode_optional::<_, ReasonCode>(reason) })
+            }
+        };
+        Decision { kind: Some(kind) }
+    }
+}
+
+impl From<Warning> for Option<crate::Warning> {
+    fn from(warning: Warning) -> Self {
+        Some(match warning.kind? {
+            warning::Kind::PossibleSpoof(w) => crate::Warning::PossibleSpoof { lookalike_of: w.lookalike_of },
+            warning::Kind::MixedScript(w) => crate::Warning::MixedScript { label: w.label },
+            warning::Kind::CredentialsStripped(w) => {
+                crate::Warning::CredentialsStripped { username: w.username, had_password: w.had_password }
+            }
+            warning::Kind::Reputation(w) => crate::Warning::Reputation { threat: w.threat },
+            warning::Kind::ExecutableFile(w) => crate::Warning::ExecutableFile { extension: w.extension },
+        })
+    }
+}
+
+impl From<crate::Intent> for Intent {
+    fn from(intent: crate::Intent) -> Self {
+        let kind = match intent {
+            crate::Intent::Time { place } => intent::Kind::Time(intent::Time { place }),
+            crate::Intent::Date { date } => intent::Kind::Date(intent::Date { date }),
+            crate::Intent::Timer { seconds } => intent::Kind::Timer(intent::Timer { seconds }),
+        };
+        Intent { kind: Some(kind) }
+    }
+}
+
+impl From<Intent> for Option<crate::Intent> {
+    fn from(intent: Intent) -> Self {
+        Some(match intent.kind? {
+            intent::Kind::Time(i) => crate::Intent::Time { place: i.place },
+            intent::Kind::Date(i) => crate::Intent::Date { date: i.date },
+            intent::Kind::Timer(i) => crate::Intent::Timer { seconds: i.seconds },
+        })
+    }
+}
+
+impl From<crate::IdnaError> for IdnaError {
+    fn from(error: crate::IdnaError) -> Self {
+        let kind = match error {
+            crate::IdnaError::InvalidPunycode { label } => idna_error::Kind::InvalidPunycode(idna_error::InvalidPunycode { label }),
+            crate::IdnaError::DisallowedCharacter { character } => {
+                idna_error::Kind::DisallowedCharacter(idna_error::DisallowedCharacter { character })
+            }
+            crate::IdnaError::Invalid => idna_error::Kind::Invalid(idna_error::Invalid {}),
+        };
+        IdnaError { kind: Some(kind) }
+    }
+}
+
+impl From<IdnaError> for Option<crate::IdnaError> {
+    fn from(error: IdnaError) -> Self {
+        Some(match error.kind? {
+            idna_error::Kind::InvalidPunycode(e) => crate::IdnaError::InvalidPunycode { label: e.label },
+            idna_error::Kind::DisallowedCharacter(e) => crate::IdnaError::DisallowedCharacter { character: e.character },
+            idna_error::Kind::Invalid(_) => crate::IdnaError::Invalid,
+        })
+    }
+}
+
+/// For Rust clients reading decisions off the wire; fails when no decision kind is set.
+impl TryFrom<Decision> for crate::Decision {
+    type Error = String;
+
+    fn try_from(decision: Decision) -> Result<Self, String> {
+        Ok(match decision.kind.ok_or("decision has no kind")? {
+            decision::Kind::Navigate(n) => crate::Decision::Navigate {
+                url: n.url,
+                search_alternative: n.search_alternative,
+                warnings: n.warnings.into_iter().filter_map(Option::from).collect(),
+                display_host: n.display_host,
+                transforms: n.transforms.into_iter().filter_map(Transform::decode_value).collect(),
+                idna_processing: IdnaProcessing::decode_value(n.idna_processing),
+                file_kind: FileKind::decode_value(n.file_kind),
+            },
+            decision::Kind::Search(s) => crate::Decision::Search {
+                query: s.query,
+                unknown_scheme_navigation: s.unknown_scheme_navigation,
+                reason: ReasonCode::decode_value(s.reason),
+                hint: QueryHint::decode_value(s.hint),
+                did_you_mean: s.did_you_mean,
+                intent: s.intent.and_then(Option::from),
+                idna_error: s.idna_error.and_then(Option::from),
+            },
+            decision::Kind::Blocked(b) => {
+                crate::Decision::Blocked { url: b.url, threat: b.threat, reason: ReasonCode::decode_value(b.reason) }
+            }
+        })
+    }
+}
@@ -0,0 +1,898 @@
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Policy {
+    #[prost(bool, optional, tag = "1")]
+    pub allow_intranet_multi_label: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag = "2")]
+    pub allow_intranet_single_label: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag = "3")]
+    pub allow_private_suffix: ::core::option::Option<bool>,
+    #[prost(message, optional, tag = "4")]
+    pub allowed_schemes: ::core::option::Option<StringSet>,
+    #[prost(bool, optional, tag = "5")]
+    pub allow_file_paths: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag = "6")]
+    pub allow_device_paths: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag = "7")]
+    pub require_existing_file_paths: ::core::option::Option<bool>,
+    #[prost(message, optional, tag = "8")]
+    pub file_extension_labels: ::core::option::Option<StringSet>,
+    #[prost(bool, optional, tag = "9")]
+    pub extract_search_queries: ::core::option::Option<bool>,
+    #[prost(enumeration = "EmailHandling", tag = "10")]
+    pub email_handling: i32,
+    #[prost(enumeration = "SpoofAction", tag = "11")]
+    pub spoof_action: i32,
+    #[prost(enumeration = "SpoofAction", tag = "12")]
+    pub mixed_script_action: i32,
+    #[prost(enumeration = "UserinfoHandling", tag = "13")]
+    pub userinfo_handling: i32,
+    #[prost(message, optional, tag = "14")]
+    pub data_urls: ::core::option::Option<DataUrlPolicy>,
+    #[prost(enumeration = "IdnaProcessing", tag = "15")]
+    pub idna_processing: i32,
+    #[prost(enumeration = "SpoofAction", tag = "16")]
+    pub executable_file_action: i32,
+    #[prost(enumeration = "ShellSchemeAction", tag = "17")]
+    pub shell_scheme_action: i32,
+    #[prost(btree_map = "string, string", tag = "18")]
+    pub keywords: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    #[prost(message, optional, tag = "19")]
+    pub rules: ::core::option::Option<Rules>,
+    #[prost(uint32, optional, tag = "20")]
+    pub max_suggestions: ::core::option::Option<u32>,
+    #[prost(enumeration = "InvalidUtf8Handling", tag = "21")]
+    pub invalid_utf8: i32,
+    #[prost(enumeration = "PlatformParity", tag = "22")]
+    pub parity: i32,
+    #[prost(enumeration = "PhoneHandling", tag = "23")]
+    pub phone_handling: i32,
+    #[prost(message, optional, tag = "24")]
+    pub normalization: ::core::option::Option<Normalization>,
+    #[prost(enumeration = "FragmentHandling", tag = "25")]
+    pub fragment_handling: i32,
+    #[prost(enumeration = "QueryEncoding", tag = "26")]
+    pub query_encoding: i32,
+    #[prost(bool, optional, tag = "27")]
+    pub search_www_single_label: ::core::option::Option<bool>,
+    #[prost(enumeration = "DefaultScheme", tag = "28")]
+    pub default_scheme: i32,
+    #[prost(bool, optional, tag = "29")]
+    pub navigate_digits_only: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag = "30")]
+    pub navigate_single_label_with_path: ::core::option::Option<bool>,
+}
+/// A set of strings; unlike a bare repeated field, an empty set differs from an unset one.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StringSet {
+    #[prost(string, repeated, tag = "1")]
+    pub values: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataUrlPolicy {
+    #[prost(uint64, optional, tag = "1")]
+    pub max_length: ::core::option::Option<u64>,
+    #[prost(message, optional, tag = "2")]
+    pub allowed_mime_types: ::core::option::Option<StringSet>,
+    #[prost(enumeration = "DataUrlAction", tag = "3")]
+    pub script_action: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Normalization {
+    #[prost(bool, optional, tag = "1")]
+    pub lowercase_host: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag = "2")]
+    pub strip_default_port: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag = "3")]
+    pub collapse_slashes: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag = "4")]
+    pub remove_fragment: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag = "5")]
+    pub strip_tracking_params: ::core::option::Option<bool>,
+    #[prost(message, optional, tag = "6")]
+    pub tracking_params: ::core::option::Option<StringSet>,
+    #[prost(bool, optional, tag = "7")]
+    pub bare_host_slash: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag = "8")]
+    pub keep_trailing_slash: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag = "9")]
+    pub strip_www: ::core::option::Option<bool>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Rules {
+    #[prost(enumeration = "Rule", repeated, tag = "1")]
+    pub order: ::prost::alloc::vec::Vec<i32>,
+    #[prost(enumeration = "Rule", repeated, tag = "2")]
+    pub disabled: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Decision {
+    #[prost(oneof = "decision::Kind", tags = "1, 2, 3")]
+    pub kind: ::core::option::Option<decision::Kind>,
+}
+/// Nested message and enum types in `Decision`.
+pub mod decision {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        Navigate(super::Navigate),
+        #[prost(message, tag = "2")]
+        Search(super::Search),
+        #[prost(message, tag = "3")]
+        Blocked(super::Blocked),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Navigate {
+    #[prost(string, tag = "1")]
+    pub url: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub search_alternative: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "3")]
+    pub warnings: ::prost::alloc::vec::Vec<Warning>,
+    #[prost(string, optional, tag = "4")]
+    pub display_host: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "Transform", repeated, tag = "5")]
+    pub transforms: ::prost::alloc::vec::Vec<i32>,
+    #[prost(enumeration = "IdnaProcessing", tag = "6")]
+    pub idna_processing: i32,
+    #[prost(enumeration = "FileKind", tag = "7")]
+    pub file_kind: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Search {
+    #[prost(string, tag = "1")]
+    pub query: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub unknown_scheme_navigation: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+    #[prost(enumeration = "ReasonCode", tag = "3")]
+    pub reason: i32,
+    #[prost(enumeration = "QueryHint", tag = "4")]
+    pub hint: i32,
+    #[prost(string, optional, tag = "5")]
+    pub did_you_mean: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "6")]
+    pub intent: ::core::option::Option<Intent>,
+    #[prost(message, optional, tag = "7")]
+    pub idna_error: ::core::option::Option<IdnaError>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Intent {
+    #[prost(oneof = "intent::Kind", tags = "1, 2, 3")]
+    pub kind: ::core::option::Option<intent::Kind>,
+}
+/// Nested message and enum types in `Intent`.
+pub mod intent {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Time {
+        #[prost(string, optional, tag = "1")]
+        pub place: ::core::option::Option<::prost::alloc::string::String>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Date {
+        /// ISO 8601, e.g. 2025-12-25
+        #[prost(string, tag = "1")]
+        pub date: ::prost::alloc::string::String,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Timer {
+        #[prost(uint64, tag = "1")]
+        pub seconds: u64,
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        Time(Time),
+        #[prost(message, tag = "2")]
+        Date(Date),
+        #[prost(message, tag = "3")]
+        Timer(Timer),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Blocked {
+    #[prost(string, tag = "1")]
+    pub url: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub threat: ::prost::alloc::string::String,
+    #[prost(enumeration = "ReasonCode", tag = "3")]
+    pub reason: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IdnaError {
+    #[prost(oneof = "idna_error::Kind", tags = "1, 2, 3")]
+    pub kind: ::core::option::Option<idna_error::Kind>,
+}
+/// Nested message and enum types in `IdnaError`.
+pub mod idna_error {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct InvalidPunycode {
+        #[prost(string, tag = "1")]
+        pub label: ::prost::alloc::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DisallowedCharacter {
+        #[prost(string, tag = "1")]
+        pub character: ::prost::alloc::string::String,
+    }
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Invalid {}
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        InvalidPunycode(InvalidPunycode),
+        #[prost(message, tag = "2")]
+        DisallowedCharacter(DisallowedCharacter),
+        #[prost(message, tag = "3")]
+        Invalid(Invalid),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Warning {
+    #[prost(oneof = "warning::Kind", tags = "1, 2, 3, 4, 5")]
+    pub kind: ::core::option::Option<warning::Kind>,
+}
+/// Nested message and enum types in `Warning`.
+pub mod warning {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PossibleSpoof {
+        #[prost(string, optional, tag = "1")]
+        pub lookalike_of: ::core::option::Option<::prost::alloc::string::String>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct MixedScript {
+        #[prost(string, tag = "1")]
+        pub label: ::prost::alloc::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CredentialsStripped {
+        #[prost(string, tag = "1")]
+        pub username: ::prost::alloc::string::String,
+        #[prost(bool, tag = "2")]
+        pub had_password: bool,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Reputation {
+        #[prost(string, tag = "1")]
+        pub threat: ::prost::alloc::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ExecutableFile {
+        #[prost(string, tag = "1")]
+        pub extension: ::prost::alloc::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        PossibleSpoof(PossibleSpoof),
+        #[prost(message, tag = "2")]
+        MixedScript(MixedScript),
+        #[prost(message, tag = "3")]
+        CredentialsStripped(CredentialsStripped),
+        #[prost(message, tag = "4")]
+        Reputation(Reputation),
+        #[prost(message, tag = "5")]
+        ExecutableFile(ExecutableFile),
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EmailHandling {
+    Unspecified = 0,
+    Search = 1,
+    Navigate = 2,
+    Mailto = 3,
+}
+impl EmailHandling {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "EMAIL_HANDLING_UNSPECIFIED",
+            Self::Search => "EMAIL_HANDLING_SEARCH",
+            Self::Navigate => "EMAIL_HANDLING_NAVIGATE",
+            Self::Mailto => "EMAIL_HANDLING_MAILTO",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EMAIL_HANDLING_UNSPECIFIED" => Some(Self::Unspecified),
+            "EMAIL_HANDLING_SEARCH" => Some(Self::Search),
+            "EMAIL_HANDLING_NAVIGATE" => Some(Self::Navigate),
+            "EMAIL_HANDLING_MAILTO" => Some(Self::Mailto),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FragmentHandling {
+    Unspecified = 0,
+    Keep = 1,
+    Strip = 2,
+    NavigateSingleLabel = 3,
+}
+impl FragmentHandling {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "FRAGMENT_HANDLING_UNSPECIFIED",
+            Self::Keep => "FRAGMENT_HANDLING_KEEP",
+            Self::Strip => "FRAGMENT_HANDLING_STRIP",
+            Self::NavigateSingleLabel => "FRAGMENT_HANDLING_NAVIGATE_SINGLE_LABEL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FRAGMENT_HANDLING_UNSPECIFIED" => Some(Self::Unspecified),
+            "FRAGMENT_HANDLING_KEEP" => Some(Self::Keep),
+            "FRAGMENT_HANDLING_STRIP" => Some(Self::Strip),
+            "FRAGMENT_HANDLING_NAVIGATE_SINGLE_LABEL" => Some(Self::NavigateSingleLabel),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DefaultScheme {
+    Unspecified = 0,
+    Http = 1,
+    Https = 2,
+}
+impl DefaultScheme {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "DEFAULT_SCHEME_UNSPECIFIED",
+            Self::Http => "DEFAULT_SCHEME_HTTP",
+            Self::Https => "DEFAULT_SCHEME_HTTPS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DEFAULT_SCHEME_UNSPECIFIED" => Some(Self::Unspecified),
+            "DEFAULT_SCHEME_HTTP" => Some(Self::Http),
+            "DEFAULT_SCHEME_HTTPS" => Some(Self::Https),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum QueryEncoding {
+    Unspecified = 0,
+    Preserve = 1,
+    ReencodeSpaces = 2,
+    Strict = 3,
+}
+impl QueryEncoding {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "QUERY_ENCODING_UNSPECIFIED",
+            Self::Preserve => "QUERY_ENCODING_PRESERVE",
+            Self::ReencodeSpaces => "QUERY_ENCODING_REENCODE_SPACES",
+            Self::Strict => "QUERY_ENCODING_STRICT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "QUERY_ENCODING_UNSPECIFIED" => Some(Self::Unspecified),
+            "QUERY_ENCODING_PRESERVE" => Some(Self::Preserve),
+            "QUERY_ENCODING_REENCODE_SPACES" => Some(Self::ReencodeSpaces),
+            "QUERY_ENCODING_STRICT" => Some(Self::Strict),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PhoneHandling {
+    Unspecified = 0,
+    Search = 1,
+    Dial = 2,
+}
+impl PhoneHandling {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "PHONE_HANDLING_UNSPECIFIED",
+            Self::Search => "PHONE_HANDLING_SEARCH",
+            Self::Dial => "PHONE_HANDLING_DIAL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PHONE_HANDLING_UNSPECIFIED" => Some(Self::Unspecified),
+            "PHONE_HANDLING_SEARCH" => Some(Self::Search),
+            "PHONE_HANDLING_DIAL" => Some(Self::Dial),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SpoofAction {
+    Unspecified = 0,
+    Allow = 1,
+    Warn = 2,
+    Search = 3,
+}
+impl SpoofAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SPOOF_ACTION_UNSPECIFIED",
+            Self::Allow => "SPOOF_ACTION_ALLOW",
+            Self::Warn => "SPOOF_ACTION_WARN",
+            Self::Search => "SPOOF_ACTION_SEARCH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SPOOF_ACTION_UNSPECIFIED" => Some(Self::Unspecified),
+            "SPOOF_ACTION_ALLOW" => Some(Self::Allow),
+            "SPOOF_ACTION_WARN" => Some(Self::Warn),
+            "SPOOF_ACTION_SEARCH" => Some(Self::Search),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum UserinfoHandling {
+    Unspecified = 0,
+    Keep = 1,
+    StripAndWarn = 2,
+    Search = 3,
+}
+impl UserinfoHandling {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "USERINFO_HANDLING_UNSPECIFIED",
+            Self::Keep => "USERINFO_HANDLING_KEEP",
+            Self::StripAndWarn => "USERINFO_HANDLING_STRIP_AND_WARN",
+            Self::Search => "USERINFO_HANDLING_SEARCH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "USERINFO_HANDLING_UNSPECIFIED" => Some(Self::Unspecified),
+            "USERINFO_HANDLING_KEEP" => Some(Self::Keep),
+            "USERINFO_HANDLING_STRIP_AND_WARN" => Some(Self::StripAndWarn),
+            "USERINFO_HANDLING_SEARCH" => Some(Self::Search),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DataUrlAction {
+    Unspecified = 0,
+    Search = 1,
+    Block = 2,
+}
+impl DataUrlAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "DATA_URL_ACTION_UNSPECIFIED",
+            Self::Search => "DATA_URL_ACTION_SEARCH",
+            Self::Block => "DATA_URL_ACTION_BLOCK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DATA_URL_ACTION_UNSPECIFIED" => Some(Self::Unspecified),
+            "DATA_URL_ACTION_SEARCH" => Some(Self::Search),
+            "DATA_URL_ACTION_BLOCK" => Some(Self::Block),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum IdnaProcessing {
+    Unspecified = 0,
+    Nontransitional = 1,
+    Transitional = 2,
+}
+impl IdnaProcessing {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "IDNA_PROCESSING_UNSPECIFIED",
+            Self::Nontransitional => "IDNA_PROCESSING_NONTRANSITIONAL",
+            Self::Transitional => "IDNA_PROCESSING_TRANSITIONAL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "IDNA_PROCESSING_UNSPECIFIED" => Some(Self::Unspecified),
+            "IDNA_PROCESSING_NONTRANSITIONAL" => Some(Self::Nontransitional),
+            "IDNA_PROCESSING_TRANSITIONAL" => Some(Self::Transitional),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ShellSchemeAction {
+    Unspecified = 0,
+    Search = 1,
+    Block = 2,
+}
+impl ShellSchemeAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SHELL_SCHEME_ACTION_UNSPECIFIED",
+            Self::Search => "SHELL_SCHEME_ACTION_SEARCH",
+            Self::Block => "SHELL_SCHEME_ACTION_BLOCK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SHELL_SCHEME_ACTION_UNSPECIFIED" => Some(Self::Unspecified),
+            "SHELL_SCHEME_ACTION_SEARCH" => Some(Self::Search),
+            "SHELL_SCHEME_ACTION_BLOCK" => Some(Self::Block),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum InvalidUtf8Handling {
+    Unspecified = 0,
+    Replace = 1,
+    Search = 2,
+}
+impl InvalidUtf8Handling {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "INVALID_UTF8_HANDLING_UNSPECIFIED",
+            Self::Replace => "INVALID_UTF8_HANDLING_REPLACE",
+            Self::Search => "INVALID_UTF8_HANDLING_SEARCH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "INVALID_UTF8_HANDLING_UNSPECIFIED" => Some(Self::Unspecified),
+            "INVALID_UTF8_HANDLING_REPLACE" => Some(Self::Replace),
+            "INVALID_UTF8_HANDLING_SEARCH" => Some(Self::Search),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PlatformParity {
+    Unspecified = 0,
+    None = 1,
+    MacOs = 2,
+    Windows = 3,
+    Chromium = 4,
+    Safari = 5,
+}
+impl PlatformParity {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "PLATFORM_PARITY_UNSPECIFIED",
+            Self::None => "PLATFORM_PARITY_NONE",
+            Self::MacOs => "PLATFORM_PARITY_MAC_OS",
+            Self::Windows => "PLATFORM_PARITY_WINDOWS",
+            Self::Chromium => "PLATFORM_PARITY_CHROMIUM",
+            Self::Safari => "PLATFORM_PARITY_SAFARI",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PLATFORM_PARITY_UNSPECIFIED" => Some(Self::Unspecified),
+            "PLATFORM_PARITY_NONE" => Some(Self::None),
+            "PLATFORM_PARITY_MAC_OS" => Some(Self::MacOs),
+            "PLATFORM_PARITY_WINDOWS" => Some(Self::Windows),
+            "PLATFORM_PARITY_CHROMIUM" => Some(Self::Chromium),
+            "PLATFORM_PARITY_SAFARI" => Some(Self::Safari),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Rule {
+    Unspecified = 0,
+    Bang = 1,
+    Keyword = 2,
+    AbsoluteUrl = 3,
+    CjkQuery = 4,
+    SchemeRelative = 5,
+    FilePath = 6,
+    CalculationOrConversion = 7,
+    Whitespace = 8,
+    EmailAddress = 9,
+    HostLike = 10,
+    WwwPrefix = 11,
+    VisitedHost = 12,
+    DigitsOnly = 13,
+}
+impl Rule {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "RULE_UNSPECIFIED",
+            Self::Bang => "RULE_BANG",
+            Self::Keyword => "RULE_KEYWORD",
+            Self::AbsoluteUrl => "RULE_ABSOLUTE_URL",
+            Self::CjkQuery => "RULE_CJK_QUERY",
+            Self::SchemeRelative => "RULE_SCHEME_RELATIVE",
+            Self::FilePath => "RULE_FILE_PATH",
+            Self::CalculationOrConversion => "RULE_CALCULATION_OR_CONVERSION",
+            Self::Whitespace => "RULE_WHITESPACE",
+            Self::EmailAddress => "RULE_EMAIL_ADDRESS",
+            Self::HostLike => "RULE_HOST_LIKE",
+            Self::WwwPrefix => "RULE_WWW_PREFIX",
+            Self::VisitedHost => "RULE_VISITED_HOST",
+            Self::DigitsOnly => "RULE_DIGITS_ONLY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RULE_UNSPECIFIED" => Some(Self::Unspecified),
+            "RULE_BANG" => Some(Self::Bang),
+            "RULE_KEYWORD" => Some(Self::Keyword),
+            "RULE_ABSOLUTE_URL" => Some(Self::AbsoluteUrl),
+            "RULE_CJK_QUERY" => Some(Self::CjkQuery),
+            "RULE_SCHEME_RELATIVE" => Some(Self::SchemeRelative),
+            "RULE_FILE_PATH" => Some(Self::FilePath),
+            "RULE_CALCULATION_OR_CONVERSION" => Some(Self::CalculationOrConversion),
+            "RULE_WHITESPACE" => Some(Self::Whitespace),
+            "RULE_EMAIL_ADDRESS" => Some(Self::EmailAddress),
+            "RULE_HOST_LIKE" => Some(Self::HostLike),
+            "RULE_WWW_PREFIX" => Some(Self::WwwPrefix),
+            "RULE_VISITED_HOST" => Some(Self::VisitedHost),
+            "RULE_DIGITS_ONLY" => Some(Self::DigitsOnly),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Transform {
+    Unspecified = 0,
+    HstsUpgrade = 1,
+    Bang = 2,
+    Keyword = 3,
+    TopHit = 4,
+    TrackingParamsStripped = 5,
+    TabsAndNewlinesStripped = 6,
+}
+impl Transform {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "TRANSFORM_UNSPECIFIED",
+            Self::HstsUpgrade => "TRANSFORM_HSTS_UPGRADE",
+            Self::Bang => "TRANSFORM_BANG",
+            Self::Keyword => "TRANSFORM_KEYWORD",
+            Self::TopHit => "TRANSFORM_TOP_HIT",
+            Self::TrackingParamsStripped => "TRANSFORM_TRACKING_PARAMS_STRIPPED",
+            Self::TabsAndNewlinesStripped => "TRANSFORM_TABS_AND_NEWLINES_STRIPPED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TRANSFORM_UNSPECIFIED" => Some(Self::Unspecified),
+            "TRANSFORM_HSTS_UPGRADE" => Some(Self::HstsUpgrade),
+            "TRANSFORM_BANG" => Some(Self::Bang),
+            "TRANSFORM_KEYWORD" => Some(Self::Keyword),
+            "TRANSFORM_TOP_HIT" => Some(Self::TopHit),
+            "TRANSFORM_TRACKING_PARAMS_STRIPPED" => Some(Self::TrackingParamsStripped),
+            "TRANSFORM_TABS_AND_NEWLINES_STRIPPED" => Some(Self::TabsAndNewlinesStripped),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FileKind {
+    Unspecified = 0,
+    Html = 1,
+    Pdf = 2,
+    Media = 3,
+    Executable = 4,
+}
+impl FileKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "FILE_KIND_UNSPECIFIED",
+            Self::Html => "FILE_KIND_HTML",
+            Self::Pdf => "FILE_KIND_PDF",
+            Self::Media => "FILE_KIND_MEDIA",
+            Self::Executable => "FILE_KIND_EXECUTABLE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FILE_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "FILE_KIND_HTML" => Some(Self::Html),
+            "FILE_KIND_PDF" => Some(Self::Pdf),
+            "FILE_KIND_MEDIA" => Some(Self::Media),
+            "FILE_KIND_EXECUTABLE" => Some(Self::Executable),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum QueryHint {
+    Unspecified = 0,
+    Navigational = 1,
+    Informational = 2,
+}
+impl QueryHint {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "QUERY_HINT_UNSPECIFIED",
+            Self::Navigational => "QUERY_HINT_NAVIGATIONAL",
+            Self::Informational => "QUERY_HINT_INFORMATIONAL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "QUERY_HINT_UNSPECIFIED" => Some(Self::Unspecified),
+            "QUERY_HINT_NAVIGATIONAL" => Some(Self::Navigational),
+            "QUERY_HINT_INFORMATIONAL" => Some(Self::Informational),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ReasonCode {
+    Unspecified = 0,
+    Calculation = 1,
+    Conversion = 2,
+    EmailAddress = 3,
+    PossibleSpoof = 4,
+    MixedScript = 5,
+    Credentials = 6,
+    Reputation = 7,
+    DataUrlTooLong = 8,
+    DataUrlMimeType = 9,
+    DataUrlScript = 10,
+    ShellScheme = 11,
+    ExecutableFile = 12,
+    InvalidEncoding = 13,
+    PhoneNumber = 14,
+    Number = 15,
+    Date = 16,
+    IdnaError = 17,
+    DigitsOnly = 18,
+}
+impl ReasonCode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "REASON_CODE_UNSPECIFIED",
+            Self::Calculation => "REASON_CODE_CALCULATION",
+            Self::Conversion => "REASON_CODE_CONVERSION",
+            Self::EmailAddress => "REASON_CODE_EMAIL_ADDRESS",
+            Self::PossibleSpoof => "REASON_CODE_POSSIBLE_SPOOF",
+            Self::MixedScript => "REASON_CODE_MIXED_SCRIPT",
+            Self::Credentials => "REASON_CODE_CREDENTIALS",
+            Self::Reputation => "REASON_CODE_REPUTATION",
+            Self::DataUrlTooLong => "REASON_CODE_DATA_URL_TOO_LONG",
+            Self::DataUrlMimeType => "REASON_CODE_DATA_URL_MIME_TYPE",
+            Self::DataUrlScript => "REASON_CODE_DATA_URL_SCRIPT",
+            Self::ShellScheme => "REASON_CODE_SHELL_SCHEME",
+            Self::ExecutableFile => "REASON_CODE_EXECUTABLE_FILE",
+            Self::InvalidEncoding => "REASON_CODE_INVALID_ENCODING",
+            Self::PhoneNumber => "REASON_CODE_PHONE_NUMBER",
+            Self::Number => "REASON_CODE_NUMBER",
+            Self::Date => "REASON_CODE_DATE",
+            Self::IdnaError => "REASON_CODE_IDNA_ERROR",
+            Self::DigitsOnly => "REASON_CODE_DIGITS_ONLY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "REASON_CODE_UNSPECIFIED" => Some(Self::Unspecified),
+            "REASON_CODE_CALCULATION" => Some(Self::Calculation),
+            "REASON_CODE_CONVERSION" => Some(Self::Conversion),
+            "REASON_CODE_EMAIL_ADDRESS" => Some(Self::EmailAddress),
+            "REASON_CODE_POSSIBLE_SPOOF" => Some(Self::PossibleSpoof),
+            "REASON_CODE_MIXED_SCRIPT" => Some(Self::MixedScript),
+            "REASON_CODE_CREDENTIALS" => Some(Self::Credentials),
+            "REASON_CODE_REPUTATION" => Some(Self::Reputation),
+            "REASON_CODE_DATA_URL_TOO_LONG" => Some(Self::DataUrlTooLong),
+            "REASON_CODE_DATA_URL_MIME_TYPE" => Some(Self::DataUrlMimeType),
+            "REASON_CODE_DATA_URL_SCRIPT" => Some(Self::DataUrlScript),
+            "REASON_CODE_SHELL_SCHEME" => Some(Self::ShellScheme),
+            "REASON_CODE_EXECUTABLE_FILE" => Some(Self::ExecutableFile),
+            "REASON_CODE_INVALID_ENCODING" => Some(Self::InvalidEncoding),
+            "REASON_CODE_PHONE_NUMBER" => Some(Self::PhoneNumber),
+            "REASON_CODE_NUMBER" => Some(Self::Number),
+            "REASON_CODE_DATE" => Some(Self::Date),
+            "REASON_CODE_IDNA_ERROR" => Some(Self::IdnaError),
+            "REASON_CODE_DIGITS_ONLY" => Some(Self::DigitsOnly),
+            _ => None,
+        }
+    }
+}
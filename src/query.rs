@@ -0,0 +1,696 @@
+//! Query heuristics
+//!
+//! Inputs that are clearly searches (calculations, conversions, ...) are detected
+//! explicitly so the decision can carry a reason code the UI can act on.
+
+use std::collections::BTreeSet;
+
+use crate::script::is_cjk;
+use crate::{QueryHint, ReasonCode};
+
+/// Returns the reason code for inputs that are recognizably searches.
+/// `locale` decides whether calculator input may use a decimal comma (`1,5*2`),
+/// which national phone number formats are recognized (`555-123-4567`) and
+/// the day/month order of dates.
+pub(crate) fn search_reason(input: &str, locale: Option<&str>) -> Option<ReasonCode> {
+    if phone_number_e164(input, locale).is_some() {
+        return Some(ReasonCode::PhoneNumber);
+    }
+    if parse_date(input, locale).is_some() {
+        return Some(ReasonCode::Date);
+    }
+    if is_math_expression(input, locale.is_some_and(uses_decimal_comma)) {
+        return Some(ReasonCode::Calculation);
+    }
+    if is_conversion_query(input) {
+        return Some(ReasonCode::Conversion);
+    }
+    if is_formatted_number(input, locale) {
+        return Some(ReasonCode::Number);
+    }
+    None
+}
+
+/// Functions recognized inside calculator expressions, e.g. `sqrt(2)*3`.
+const MATH_FUNCTIONS: &[&str] = &[
+    "abs", "acos", "asin", "atan", "cbrt", "cos", "exp", "ln", "log", "sin", "sqrt", "tan",
+];
+
+/// Named constants recognized inside calculator expressions.
+const MATH_CONSTANTS: &[&str] = &["e", "pi", "π"];
+
+/// Languages writing `1,5` for one and a half.
+const DECIMAL_COMMA_LANGUAGES: &[&str] = &[
+    "bg", "ca", "cs", "da", "de", "el", "es", "et", "fi", "fr", "hr", "hu", "id", "it", "lt", "lv", "nb", "nl", "no",
+    "pl", "pt", "ro", "ru", "sk", "sl", "sr", "sv", "tr", "uk", "vi",
+];
+
+/// Regions of [`DECIMAL_COMMA_LANGUAGES`] that use a decimal point nonetheless.
+const DECIMAL_POINT_LOCALES: &[&str] = &["de-ch", "de-li", "it-ch", "es-mx", "es-us"];
+
+/// Whether a BCP 47 locale such as `de-DE` or `pt_BR` writes decimals with a comma.
+pub(crate) fn uses_decimal_comma(locale: &str) -> bool {
+    let locale = locale.replace('_', "-").to_ascii_lowercase();
+    let language = locale.split('-').next().unwrap_or_default();
+    let region = locale.split('-').skip(1).find(|subtag| subtag.len() == 2);
+    let language_region = region.map(|region| format!("{}-{}", language, region));
+    DECIMAL_COMMA_LANGUAGES.contains(&language)
+        && !language_region.is_some_and(|lr| DECIMAL_POINT_LOCALES.contains(&lr.as_str()))
+}
+
+/// Region subtag of a BCP 47 locale, uppercase: `US` for `en-US` or `en_us`.
+fn locale_region(locale: &str) -> Option<String> {
+    let region = locale.split(['-', '_']).skip(1).find(|subtag| subtag.len() == 2)?;
+    region.bytes().all(|b| b.is_ascii_alphabetic()).then(|| region.to_ascii_uppercase())
+}
+
+/// Languages written right to left.
+const RTL_LANGUAGES: &[&str] = &["ar", "ckb", "dv", "fa", "he", "iw", "ps", "sd", "ug", "ur", "yi"];
+
+/// Whether a BCP 47 locale such as `ar-EG` or `he` is written right to left.
+pub(crate) fn is_rtl_locale(locale: &str) -> bool {
+    let language = locale.split(['-', '_']).next().unwrap_or_default().to_ascii_lowercase();
+    RTL_LANGUAGES.contains(&language.as_str())
+}
+
+/// How numbers are dialed within a region, without the `+` country code.
+struct NationalFormat {
+    regions: &'static [&'static str],
+    /// Leading digit every national number carries, e.g. `0` in `030 1234567`
+    trunk_prefix: Option<char>,
+    /// Digit count, trunk prefix included
+    digits: std::ops::RangeInclusive<usize>,
+}
+
+const NATIONAL_FORMATS: &[NationalFormat] = &[
+    NationalFormat { regions: &["US", "CA"], trunk_prefix: None, digits: 10..=10 },
+    NationalFormat { regions: &["GB", "BR", "JP"], trunk_prefix: Some('0'), digits: 10..=11 },
+    NationalFormat { regions: &["DE", "AT"], trunk_prefix: Some('0'), digits: 6..=13 },
+    NationalFormat { regions: &["FR", "NL", "CH", "AU"], trunk_prefix: Some('0'), digits: 10..=10 },
+    NationalFormat { regions: &["IN"], trunk_prefix: Some('0'), digits: 11..=11 },
+    NationalFormat { regions: &["BE"], trunk_prefix: Some('0'), digits: 9..=10 },
+    NationalFormat { regions: &["SE"], trunk_prefix: Some('0'), digits: 7..=10 },
+    NationalFormat { regions: &["PT", "ES", "PL"], trunk_prefix: None, digits: 9..=9 },
+    NationalFormat { regions: &["MX"], trunk_prefix: None, digits: 10..=10 },
+];
+
+/// Country calling codes of the regions in [`NATIONAL_FORMATS`].
+const CALLING_CODES: &[(&str, &str)] = &[
+    ("US", "1"), ("CA", "1"), ("GB", "44"), ("BR", "55"), ("JP", "81"), ("DE", "49"), ("AT", "43"), ("FR", "33"),
+    ("NL", "31"), ("CH", "41"), ("AU", "61"), ("IN", "91"), ("BE", "32"), ("SE", "46"), ("PT", "351"), ("ES", "34"),
+    ("PL", "48"), ("MX", "52"),
+];
+
+/// The E.164 form (`+351912345678`) of a phone number: international
+/// (`+351 912 345 678`, 8 to 15 digits) in any locale, or in the national
+/// format of `locale`'s region (`(212) 555-1234`, `555.123.4567` in `en-US`;
+/// `030 1234567` in `de-DE`), which gets the region's calling code in place of
+/// the trunk prefix.
+///
+/// Digit groups may be split by spaces, or by one kind of `-` or `.`, with the
+/// area code optionally in parentheses. National numbers without a trunk
+/// prefix need a separator, so plain numbers (`912345678`) aren't matched.
+pub(crate) fn phone_number_e164(input: &str, locale: Option<&str>) -> Option<String> {
+    let (international, rest) = match input.strip_prefix('+') {
+        Some(rest) => (true, rest),
+        None => (false, input),
+    };
+    if !rest.starts_with(|c: char| c.is_ascii_digit() || c == '(') {
+        return None;
+    }
+    if !rest.chars().all(|c| c.is_ascii_digit() || matches!(c, ' ' | '-' | '.' | '(' | ')')) {
+        return None;
+    }
+    let has_separator = !rest.bytes().all(|b| b.is_ascii_digit());
+    // `123.45.67.89` is an address
+    if rest.parse::<std::net::Ipv4Addr>().is_ok() {
+        return None;
+    }
+    let (open, close) = (rest.find('('), rest.find(')'));
+    let parens_ok = match (open, close) {
+        (None, None) => true,
+        (Some(open), Some(close)) => open < close && rest.matches(['(', ')']).count() == 2,
+        _ => false,
+    };
+    let separators: BTreeSet<char> = rest.chars().filter(|c| matches!(c, '-' | '.')).collect();
+    if !parens_ok || separators.len() > 1 || rest.contains("  ") {
+        return None;
+    }
+
+    let digits: String = rest.chars().filter(char::is_ascii_digit).collect();
+    if international {
+        return (8..=15).contains(&digits.len()).then(|| format!("+{digits}"));
+    }
+    let region = locale.and_then(locale_region)?;
+    let format = NATIONAL_FORMATS.iter().find(|f| f.regions.contains(&region.as_str()))?;
+    // NANP numbers may be dialed with the leading `1`
+    let digits = match digits.strip_prefix('1') {
+        Some(national) if format.regions.contains(&"US") && digits.len() == 11 => national,
+        _ => &digits,
+    };
+    let national = match format.trunk_prefix {
+        Some(trunk) => digits.strip_prefix(trunk)?,
+        None if has_separator => digits,
+        None => return None,
+    };
+    if !format.digits.contains(&digits.len()) {
+        return None;
+    }
+    let (_, calling_code) = CALLING_CODES.iter().find(|(r, _)| *r == region)?;
+    Some(format!("+{calling_code}{national}"))
+}
+
+/// Regions writing numeric dates month first (`12/24/2025`).
+const MONTH_FIRST_REGIONS: &[&str] = &["US", "PH", "FM", "MH", "PW"];
+
+/// The `(year, month, day)` of a calendar date with a four-digit year: ISO
+/// (`2025-12-24`), dotted day-month-year (`24.12.2025`), or with `/` or `-`
+/// in the order of `locale`'s region (`12/24/2025` in `en-US`, `24/12/2025`
+/// elsewhere; either without a locale, day first when both exist). Day and
+/// month must exist.
+pub(crate) fn parse_date(input: &str, locale: Option<&str>) -> Option<(u32, u32, u32)> {
+    let sep = input.chars().find(|c| matches!(c, '-' | '.' | '/'))?;
+    let parts: Vec<&str> = input.split(sep).collect();
+    if parts.len() != 3 || !parts.iter().all(|p| (1..=4).contains(&p.len()) && p.bytes().all(|b| b.is_ascii_digit())) {
+        return None;
+    }
+    let [a, b, c] = [parts[0], parts[1], parts[2]].map(|p| p.parse::<u32>().unwrap_or(0));
+    let ymd = if parts[0].len() == 4 {
+        (sep == '-').then_some((a, b, c))
+    } else if parts[2].len() != 4 || parts[0].len() > 2 || parts[1].len() > 2 {
+        None
+    } else if sep == '.' {
+        Some((c, b, a))
+    } else {
+        let month_first = locale.and_then(locale_region).map(|region| MONTH_FIRST_REGIONS.contains(&region.as_str()));
+        match month_first {
+            Some(true) => Some((c, a, b)),
+            Some(false) => Some((c, b, a)),
+            None => [(c, b, a), (c, a, b)].into_iter().find(|&(y, m, d)| is_valid_date(y, m, d)),
+        }
+    };
+    ymd.filter(|&(y, m, d)| is_valid_date(y, m, d))
+}
+
+fn is_valid_date(year: u32, month: u32, day: u32) -> bool {
+    let leap = year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+    let days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return false,
+    };
+    year >= 1000 && (1..=days).contains(&day)
+}
+
+/// Returns `true` for an amount written with separators (`1.234,56`,
+/// `1,234.56`, `1'234`), in `locale`'s convention when there is one: in
+/// `de-DE`, `1.234,56` is a number but `1,234.56` isn't. Plain integers and
+/// dotted quads (`10.000.000.001`) don't count.
+pub(crate) fn is_formatted_number(input: &str, locale: Option<&str>) -> bool {
+    if !input.contains(['.', ',', '\'']) || !is_localized_number(input) || input.split('.').count() == 4 {
+        return false;
+    }
+    let Some(locale) = locale else {
+        return true;
+    };
+    let (group, decimal) = if uses_decimal_comma(locale) { ('.', ',') } else { (',', '.') };
+    let foreign = input.contains(|c: char| !c.is_ascii_digit() && c != group && c != decimal && c != '\'');
+    let mixed = input.contains('.') && input.contains(',');
+    !foreign && (!mixed || input.chars().rev().find(|c| !c.is_ascii_digit()) == Some(decimal))
+}
+
+/// Guards the recursive descent against inputs like `((((((...`.
+const MAX_MATH_DEPTH: usize = 32;
+
+#[derive(Debug, Clone, PartialEq)]
+enum MathToken {
+    Number,
+    Constant,
+    Function,
+    Op(char),
+    LParen,
+    RParen,
+}
+
+/// Returns `true` when `input` is a small arithmetic expression such as
+/// `1+(3+4*2)`, `2^10` or `sqrt(2)*3`.
+///
+/// This is a grammar check only, nothing is evaluated. A lone number (`42`,
+/// `-5`) is not an expression: at least one binary operator or function call
+/// is required. With `decimal_comma`, `1,5` is a number too.
+pub(crate) fn is_math_expression(input: &str, decimal_comma: bool) -> bool {
+    let Some(tokens) = tokenize_math(input, decimal_comma) else {
+        return false;
+    };
+    let has_operation = tokens.iter().any(|t| matches!(t, MathToken::Function))
+        || tokens
+            .windows(2)
+            .any(|w| matches!(w, [MathToken::Number | MathToken::Constant | MathToken::RParen, MathToken::Op(_)]));
+    if !has_operation {
+        return false;
+    }
+
+    let mut parser = MathParser { tokens: &tokens, pos: 0, depth: 0 };
+    parser.expr() && parser.pos == tokens.len()
+}
+
+fn tokenize_math(input: &str, decimal_comma: bool) -> Option<Vec<MathToken>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '0'..='9' | '.' => {
+                let is_separator = |d: char| d == '.' || (decimal_comma && d == ',');
+                let mut seen_dot = false;
+                let mut seen_digit = false;
+                while let Some(&d) = chars.peek() {
+                    if d.is_ascii_digit() {
+                        seen_digit = true;
+                    } else if is_separator(d) && !seen_dot {
+                        seen_dot = true;
+                    } else if is_separator(d) {
+                        // `1.2.3` is a version or an IP, not a number
+                        return None;
+                    } else {
+                        break;
+                    }
+                    chars.next();
+                }
+                if !seen_digit {
+                    return None;
+                }
+                tokens.push(MathToken::Number);
+            }
+            c if c.is_alphabetic() => {
+                let mut ident = String::new();
+                while let Some(&d) = chars.peek() {
+                    if !d.is_alphabetic() {
+                        break;
+                    }
+                    ident.push(d.to_ascii_lowercase());
+                    chars.next();
+                }
+                if MATH_FUNCTIONS.contains(&ident.as_str()) {
+                    tokens.push(MathToken::Function);
+                } else if MATH_CONSTANTS.contains(&ident.as_str()) {
+                    tokens.push(MathToken::Constant);
+                } else {
+                    return None;
+                }
+            }
+            '+' | '-' | '*' | '/' | '^' | '%' | '×' | '÷' => {
+                tokens.push(MathToken::Op(c));
+                chars.next();
+            }
+            '(' => {
+                tokens.push(MathToken::LParen);
+                chars.next();
+            }
+            ')' => {
+                tokens.push(MathToken::RParen);
+                chars.next();
+            }
+            _ => return None,
+        }
+    }
+
+    Some(tokens)
+}
+
+/// Recursive descent over:
+///
+/// ```text
+/// expr    := term (('+' | '-') term)*
+/// term    := power (('*' | '/' | '%' | '×' | '÷') power)*
+/// power   := unary ('^' unary)*
+/// unary   := ('+' | '-')* primary
+/// primary := number | constant | function '(' expr ')' | '(' expr ')'
+/// ```
+struct MathParser<'a> {
+    tokens: &'a [MathToken],
+    pos: usize,
+    depth: usize,
+}
+
+impl MathParser<'_> {
+    fn peek(&self) -> Option<&MathToken> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat(&mut self, token: &MathToken) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_op(&mut self, ops: &[char]) -> bool {
+        match self.peek() {
+            Some(MathToken::Op(op)) if ops.contains(op) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expr(&mut self) -> bool {
+        self.depth += 1;
+        if self.depth > MAX_MATH_DEPTH {
+            return false;
+        }
+        let mut ok = self.term();
+        while ok && self.eat_op(&['+', '-']) {
+            ok = self.term();
+        }
+        self.depth -= 1;
+        ok
+    }
+
+    fn term(&mut self) -> bool {
+        let mut ok = self.power();
+        while ok && self.eat_op(&['*', '/', '%', '×', '÷']) {
+            ok = self.power();
+        }
+        ok
+    }
+
+    fn power(&mut self) -> bool {
+        // Only acceptance matters here, so `^` can be parsed iteratively
+        let mut ok = self.unary();
+        while ok && self.eat_op(&['^']) {
+            ok = self.unary();
+        }
+        ok
+    }
+
+    fn unary(&mut self) -> bool {
+        while self.eat_op(&['+', '-']) {}
+        self.primary()
+    }
+
+    fn primary(&mut self) -> bool {
+        match self.peek() {
+            Some(MathToken::Number | MathToken::Constant) => {
+                self.pos += 1;
+                true
+            }
+            Some(MathToken::Function) => {
+                self.pos += 1;
+                self.eat(&MathToken::LParen) && self.expr() && self.eat(&MathToken::RParen)
+            }
+            Some(MathToken::LParen) => {
+                self.pos += 1;
+                self.expr() && self.eat(&MathToken::RParen)
+            }
+            _ => false,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Email addresses
+// -----------------------------------------------------------------------------
+
+/// Maximum length of an email local part (RFC 5321).
+const MAX_EMAIL_LOCAL_PART: usize = 64;
+
+/// Splits `user@example.com` into local part and domain when the local part is
+/// a valid RFC 5322 dot-atom. The domain is returned as typed; callers check it
+/// against the suffix DB.
+pub(crate) fn split_email_address(input: &str) -> Option<(&str, &str)> {
+    let (local, domain) = input.split_once('@')?;
+    if domain.contains('@') || domain.is_empty() || domain.contains(['/', ':', '?', '#']) {
+        return None;
+    }
+    if local.is_empty() || local.len() > MAX_EMAIL_LOCAL_PART {
+        return None;
+    }
+    let atext = |c: char| c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(c);
+    if !local.split('.').all(|atom| !atom.is_empty() && atom.chars().all(atext)) {
+        return None;
+    }
+    Some((local, domain))
+}
+
+// -----------------------------------------------------------------------------
+// CJK text
+// -----------------------------------------------------------------------------
+
+/// Characters IDNA maps to `.`, so `例え。テスト` is still a dotted host.
+const DOT_LIKE: &[char] = &['.', '。', '．', '｡'];
+
+/// Returns `true` for CJK text with no host structure (no dot, no scheme or
+/// port), e.g. `東京タワー`, `天気予報` or `東京/大阪`.
+///
+/// CJK queries usually contain no spaces, so the whitespace heuristic doesn't
+/// catch them and single-label intranet rules would otherwise navigate them.
+pub(crate) fn is_cjk_query(input: &str) -> bool {
+    input.chars().any(is_cjk)
+        && !input.contains(DOT_LIKE)
+        && !input.contains([':', '：'])
+        && !input.starts_with("//")
+}
+
+/// Returns `true` for a single token of digits, e.g. `3000` or `12345678`,
+/// ASCII or fullwidth (`３０００`, which IDNA maps to ASCII).
+///
+/// These are numbers, not hosts: as a host, `3000` is the IPv4 address
+/// `0.0.11.184`, and intranet or history rules could otherwise navigate them.
+pub(crate) fn is_digits_only(input: &str) -> bool {
+    !input.is_empty() && input.chars().all(|c| c.is_ascii_digit() || ('\u{FF10}'..='\u{FF19}').contains(&c))
+}
+
+// -----------------------------------------------------------------------------
+// Navigational vs informational hints
+// -----------------------------------------------------------------------------
+
+/// Leading words that make a query a question.
+const QUESTION_WORDS: &[&str] = &[
+    "who", "what", "when", "where", "why", "which", "how", "is", "are", "can", "could", "does",
+    "do", "did", "should", "will", "would",
+];
+
+/// Queries with at least this many words read as informational.
+const INFORMATIONAL_MIN_WORDS: usize = 4;
+
+/// Returns a hint on whether `query` looks like the user is trying to reach a
+/// site (`duckduckgo`, `stuff.stor`) or to learn something (`how do magnets work`).
+pub(crate) fn query_hint(query: &str) -> Option<QueryHint> {
+    let words: Vec<&str> = query.split_whitespace().collect();
+    let first = words.first()?;
+
+    if words.len() == 1 && !is_cjk_query(first) && looks_like_site_name(first) {
+        return Some(QueryHint::Navigational);
+    }
+
+    let first = first.to_lowercase();
+    if QUESTION_WORDS.contains(&first.as_str())
+        || query.ends_with('?')
+        || words.len() >= INFORMATIONAL_MIN_WORDS
+    {
+        return Some(QueryHint::Informational);
+    }
+    None
+}
+
+/// A single token resembling a brand or host: letters, digits, `-` and `.`,
+/// with at least one letter and no empty labels.
+fn looks_like_site_name(token: &str) -> bool {
+    token.len() >= 2
+        && token.len() <= 253
+        && token.chars().any(char::is_alphabetic)
+        && token.chars().all(|c| c.is_alphanumeric() || matches!(c, '-' | '.'))
+        && token.split('.').all(|label| !label.is_empty())
+}
+
+// -----------------------------------------------------------------------------
+// Unit and currency conversions
+// -----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dimension {
+    Currency,
+    Length,
+    Mass,
+    Temperature,
+    Volume,
+    Time,
+    Speed,
+    Data,
+    Area,
+}
+
+/// Words joining the two sides of a conversion: `100 usd to eur`, `5km in miles`.
+const CONVERSION_CONNECTORS: &[&str] = &["to", "in", "into", "as", "->", "→", "="];
+
+/// Currency symbols accepted in front of an amount, e.g. `$100 to eur`.
+const CURRENCY_SYMBOLS: &[char] = &['$', '€', '£', '¥', '₹', '₽', '₩'];
+
+/// Known units. A unit may appear more than once when it is ambiguous
+/// (`pound` is both a currency and a mass).
+const UNITS: &[(&str, Dimension)] = &[
+    // currencies
+    ("usd", Dimension::Currency), ("eur", Dimension::Currency), ("gbp", Dimension::Currency),
+    ("jpy", Dimension::Currency), ("cny", Dimension::Currency), ("chf", Dimension::Currency),
+    ("cad", Dimension::Currency), ("aud", Dimension::Currency), ("nzd", Dimension::Currency),
+    ("sek", Dimension::Currency), ("nok", Dimension::Currency), ("dkk", Dimension::Currency),
+    ("pln", Dimension::Currency), ("czk", Dimension::Currency), ("huf", Dimension::Currency),
+    ("rub", Dimension::Currency), ("inr", Dimension::Currency), ("brl", Dimension::Currency),
+    ("mxn", Dimension::Currency), ("krw", Dimension::Currency), ("try", Dimension::Currency),
+    ("zar", Dimension::Currency), ("hkd", Dimension::Currency), ("sgd", Dimension::Currency),
+    ("ils", Dimension::Currency), ("thb", Dimension::Currency), ("btc", Dimension::Currency),
+    ("eth", Dimension::Currency), ("dollar", Dimension::Currency), ("dollars", Dimension::Currency),
+    ("euro", Dimension::Currency), ("euros", Dimension::Currency), ("pound", Dimension::Currency),
+    ("pounds", Dimension::Currency), ("yen", Dimension::Currency), ("yuan", Dimension::Currency),
+    ("rupee", Dimension::Currency), ("rupees", Dimension::Currency), ("franc", Dimension::Currency),
+    ("francs", Dimension::Currency),
+    // length
+    ("mm", Dimension::Length), ("cm", Dimension::Length), ("m", Dimension::Length),
+    ("km", Dimension::Length), ("in", Dimension::Length), ("inch", Dimension::Length),
+    ("inches", Dimension::Length), ("ft", Dimension::Length), ("foot", Dimension::Length),
+    ("feet", Dimension::Length), ("yd", Dimension::Length), ("yard", Dimension::Length),
+    ("yards", Dimension::Length), ("mi", Dimension::Length), ("mile", Dimension::Length),
+    ("miles", Dimension::Length), ("meter", Dimension::Length), ("meters", Dimension::Length),
+    ("metre", Dimension::Length), ("metres", Dimension::Length), ("kilometer", Dimension::Length),
+    ("kilometers", Dimension::Length), ("kilometre", Dimension::Length), ("kilometres", Dimension::Length),
+    ("centimeters", Dimension::Length), ("centimetres", Dimension::Length), ("millimeters", Dimension::Length),
+    ("millimetres", Dimension::Length),
+    // mass
+    ("mg", Dimension::Mass), ("g", Dimension::Mass), ("kg", Dimension::Mass),
+    ("t", Dimension::Mass), ("lb", Dimension::Mass), ("lbs", Dimension::Mass),
+    ("pound", Dimension::Mass), ("pounds", Dimension::Mass), ("oz", Dimension::Mass),
+    ("ounce", Dimension::Mass), ("ounces", Dimension::Mass), ("stone", Dimension::Mass),
+    ("st", Dimension::Mass), ("gram", Dimension::Mass), ("grams", Dimension::Mass),
+    ("kilogram", Dimension::Mass), ("kilograms", Dimension::Mass), ("ton", Dimension::Mass),
+    ("tons", Dimension::Mass), ("tonnes", Dimension::Mass),
+    // temperature
+    ("c", Dimension::Temperature), ("f", Dimension::Temperature), ("k", Dimension::Temperature),
+    ("°c", Dimension::Temperature), ("°f", Dimension::Temperature), ("celsius", Dimension::Temperature),
+    ("fahrenheit", Dimension::Temperature), ("kelvin", Dimension::Temperature),
+    // volume
+    ("ml", Dimension::Volume), ("l", Dimension::Volume), ("liter", Dimension::Volume),
+    ("liters", Dimension::Volume), ("litre", Dimension::Volume), ("litres", Dimension::Volume),
+    ("gal", Dimension::Volume), ("gallon", Dimension::Volume), ("gallons", Dimension::Volume),
+    ("cup", Dimension::Volume), ("cups", Dimension::Volume), ("pint", Dimension::Volume),
+    ("pints", Dimension::Volume), ("quart", Dimension::Volume), ("quarts", Dimension::Volume),
+    ("floz", Dimension::Volume), ("tbsp", Dimension::Volume), ("tsp", Dimension::Volume),
+    // time
+    ("ms", Dimension::Time), ("s", Dimension::Time), ("sec", Dimension::Time),
+    ("seconds", Dimension::Time), ("min", Dimension::Time), ("minutes", Dimension::Time),
+    ("h", Dimension::Time), ("hr", Dimension::Time), ("hours", Dimension::Time),
+    ("day", Dimension::Time), ("days", Dimension::Time), ("week", Dimension::Time),
+    ("weeks", Dimension::Time), ("year", Dimension::Time), ("years", Dimension::Time),
+    // speed
+    ("mph", Dimension::Speed), ("kph", Dimension::Speed), ("kmh", Dimension::Speed),
+    ("km/h", Dimension::Speed), ("m/s", Dimension::Speed), ("knots", Dimension::Speed),
+    // data
+    ("b", Dimension::Data), ("kb", Dimension::Data), ("mb", Dimension::Data),
+    ("gb", Dimension::Data), ("tb", Dimension::Data), ("kib", Dimension::Data),
+    ("mib", Dimension::Data), ("gib", Dimension::Data), ("bytes", Dimension::Data),
+    ("bits", Dimension::Data),
+    // area
+    ("acre", Dimension::Area), ("acres", Dimension::Area), ("hectare", Dimension::Area),
+    ("hectares", Dimension::Area), ("ha", Dimension::Area), ("sqft", Dimension::Area),
+    ("sqm", Dimension::Area), ("m2", Dimension::Area), ("km2", Dimension::Area),
+];
+
+fn unit_dimensions(unit: &str) -> impl Iterator<Item = Dimension> + '_ {
+    UNITS.iter().filter(move |(u, _)| *u == unit).map(|(_, d)| *d)
+}
+
+/// Returns `true` for conversion queries such as `100 usd to eur`,
+/// `5km in miles`, `72f to c` or `$1.234,50 in eur`.
+///
+/// Both sides must be known units of the same dimension. Amounts accept
+/// either decimal separator, see [`is_localized_number`].
+pub(crate) fn is_conversion_query(input: &str) -> bool {
+    let lowered = input.to_lowercase();
+    let tokens: Vec<&str> = lowered.split_whitespace().collect();
+    if tokens.len() < 3 {
+        return false;
+    }
+
+    // `in` is both a connector and a unit (`5 in to cm`), so try every split
+    (1..tokens.len() - 1)
+        .filter(|&i| CONVERSION_CONNECTORS.contains(&tokens[i]))
+        .any(|i| {
+            let target = &tokens[i + 1..];
+            if target.len() != 1 {
+                return false;
+            }
+            let Some(source_dims) = conversion_source_dimensions(&tokens[..i]) else {
+                return false;
+            };
+            unit_dimensions(target[0]).any(|d| source_dims.contains(&d))
+        })
+}
+
+/// Parses `<amount><unit>`, `<amount> <unit>` or `<symbol><amount>` and
+/// returns the candidate dimensions of the unit.
+fn conversion_source_dimensions(tokens: &[&str]) -> Option<Vec<Dimension>> {
+    let (amount, unit) = match tokens {
+        [single] => {
+            if let Some(amount) = single.strip_prefix(CURRENCY_SYMBOLS) {
+                return is_localized_number(amount).then(|| vec![Dimension::Currency]);
+            }
+            let split = single.find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | ',' | '\'')))?;
+            single.split_at(split)
+        }
+        [amount, unit] => (*amount, *unit),
+        _ => return None,
+    };
+    if !is_localized_number(amount) {
+        return None;
+    }
+    let dims: Vec<Dimension> = unit_dimensions(unit).collect();
+    (!dims.is_empty()).then_some(dims)
+}
+
+/// Returns `true` for plain amounts written with either decimal convention:
+/// `1.5`, `1,5`, `1,234.56`, `1.234,56`, `1'234.56`.
+///
+/// Grouping separators must split the integer part into groups of three
+/// digits, and the decimal separator may only appear once, after the groups.
+pub(crate) fn is_localized_number(s: &str) -> bool {
+    if s.is_empty() || !s.starts_with(|c: char| c.is_ascii_digit()) {
+        return false;
+    }
+
+    // The last `.` or `,` followed by anything other than exactly three digits
+    // can only be a decimal separator
+    let (integer, fraction) = match s.rfind(['.', ',']) {
+        Some(pos) if s.len() - pos - 1 != 3 => (&s[..pos], Some(&s[pos + 1..])),
+        _ => (s, None),
+    };
+    if let Some(fraction) = fraction {
+        if fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit()) {
+            return false;
+        }
+    }
+
+    if integer.chars().all(|c| c.is_ascii_digit()) {
+        return !integer.is_empty();
+    }
+
+    // Grouped integer part: one kind of grouping separator, groups of three
+    let Some(sep) = integer.chars().find(|c| !c.is_ascii_digit()) else {
+        return false;
+    };
+    if !matches!(sep, '.' | ',' | '\'') {
+        return false;
+    }
+    let mut groups = integer.split(sep);
+    let first = groups.next().unwrap_or_default();
+    (1..=3).contains(&first.len())
+        && first.chars().all(|c| c.is_ascii_digit())
+        && groups.all(|g| g.len() == 3 && g.chars().all(|c| c.is_ascii_digit()))
+}
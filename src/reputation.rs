@@ -0,0 +1,26 @@
+//! Reputation checks
+//!
+//! Browsers already keep local threat lists (phishing, malware). A
+//! [`ReputationProvider`] sees the final URL of every Navigate decision and can
+//! let it through, attach warnings, or veto it.
+
+use crate::Warning;
+
+/// Consulted just before a Navigate decision is returned.
+pub trait ReputationProvider: Send + Sync + 'static {
+    /// `url` is the URL about to be navigated to, `host` its ASCII host.
+    fn check(&self, url: &str, host: &str) -> Reputation;
+}
+
+/// Verdict of a [`ReputationProvider`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reputation {
+    /// Navigate as decided
+    Allow,
+    /// Navigate, with these warnings added to the decision
+    Warn(Vec<Warning>),
+    /// Search for the input instead (with reason `Reputation`)
+    Search,
+    /// Return `Decision::Blocked` with the threat the provider matched
+    Block { threat: String },
+}
@@ -0,0 +1,33 @@
+//! Safari compatibility (`PlatformParity::Safari`)
+//!
+//! Safari hands any URL with a scheme it doesn't know to the system, which opens
+//! the registered app or reports that none is installed, rather than searching.
+//! A single word completes to the top hit from history, so `github` goes to
+//! `github.com` once the user has been there.
+
+use url::Url;
+
+use crate::HistoryProvider;
+
+/// Whether an unknown-scheme input navigates rather than searches. `host:8080`
+/// parses with scheme `host` but is a host and port, not a URL.
+pub(crate) fn navigates_unknown_scheme(input: &str) -> bool {
+    let Some((_, rest)) = input.split_once(':') else {
+        return false;
+    };
+    !rest.is_empty() && !rest.starts_with(|c: char| c.is_ascii_digit()) && !input.contains(char::is_whitespace)
+}
+
+/// The visited site a single word completes to, e.g. `http://github.com/` for
+/// `github`, trying `word.com` before `www.word.com`.
+pub(crate) fn top_hit(input: &str, history: &dyn HistoryProvider) -> Option<String> {
+    if input.is_empty() || input.contains(|c: char| !c.is_alphanumeric() && c != '-') {
+        return None;
+    }
+    let word = crate::to_idna_ascii(input)?.to_ascii_lowercase();
+    [format!("{word}.com"), format!("www.{word}.com")]
+        .into_iter()
+        .find(|host| crate::host_like_valid(host) && history.visit_count(host) > 0)
+        .and_then(|host| Url::parse(&format!("http://{host}/")).ok())
+        .map(String::from)
+}
@@ -0,0 +1,60 @@
+//! Unicode script detection
+//!
+//! A small code-point range table, enough to tell apart the scripts the
+//! classifier cares about without pulling in the full Unicode script data.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Script {
+    /// Digits, punctuation, symbols and combining marks shared by all scripts
+    Common,
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Thai,
+    Hangul,
+    Hiragana,
+    Katakana,
+    Bopomofo,
+    Han,
+    /// Any script not listed above
+    Other,
+}
+
+pub(crate) fn script_of(c: char) -> Script {
+    let cp = c as u32;
+    match cp {
+        0x41..=0x5A | 0x61..=0x7A => Script::Latin,
+        0x00..=0x7F => Script::Common,
+        0xD7 | 0xF7 => Script::Common,
+        0xC0..=0x24F | 0x250..=0x2AF | 0x1E00..=0x1EFF | 0x2C60..=0x2C7F | 0xA720..=0xA7FF | 0xAB30..=0xAB6F => Script::Latin,
+        0xFF21..=0xFF3A | 0xFF41..=0xFF5A => Script::Latin,
+        0x80..=0xBF | 0x2B0..=0x36F => Script::Common,
+        0x370..=0x3FF | 0x1F00..=0x1FFF => Script::Greek,
+        0x400..=0x52F | 0x1C80..=0x1C8F | 0x2DE0..=0x2DFF | 0xA640..=0xA69F => Script::Cyrillic,
+        0x530..=0x58F => Script::Armenian,
+        0x590..=0x5FF | 0xFB1D..=0xFB4F => Script::Hebrew,
+        0x600..=0x6FF | 0x750..=0x77F | 0x8A0..=0x8FF | 0xFB50..=0xFDFF | 0xFE70..=0xFEFF => Script::Arabic,
+        0x900..=0x97F => Script::Devanagari,
+        0xE00..=0xE7F => Script::Thai,
+        0x1100..=0x11FF | 0x3130..=0x318F | 0xA960..=0xA97F | 0xAC00..=0xD7FF | 0xFFA0..=0xFFDC => Script::Hangul,
+        0x3040..=0x309F => Script::Hiragana,
+        0x30A0..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => Script::Katakana,
+        0x3100..=0x312F | 0x31A0..=0x31BF => Script::Bopomofo,
+        0x3005 | 0x3007 | 0x3021..=0x3029 | 0x3038..=0x303B => Script::Han,
+        0x2E80..=0x2FDF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF | 0x20000..=0x3FFFF => Script::Han,
+        0x2000..=0x206F | 0x3000..=0x303F | 0xFE00..=0xFE0F | 0xFF00..=0xFFEF => Script::Common,
+        _ => Script::Other,
+    }
+}
+
+/// Chinese, Japanese and Korean scripts, which don't separate words with spaces.
+pub(crate) fn is_cjk(c: char) -> bool {
+    matches!(
+        script_of(c),
+        Script::Han | Script::Hiragana | Script::Katakana | Script::Hangul | Script::Bopomofo
+    )
+}
@@ -0,0 +1,61 @@
+//! Search-engine result page (SERP) URLs
+//!
+//! Pasted SERP URLs (`https://www.google.com/search?q=rust+lifetimes`) still
+//! navigate, but the decoded query is surfaced so the client can offer
+//! "Search DuckDuckGo for: rust lifetimes" alongside.
+
+use url::Url;
+
+/// Known engines: host (without `www.`), result page paths, query parameter.
+/// A host ending in `.` matches any suffix, e.g. `google.` → `google.co.uk`.
+const SEARCH_ENGINES: &[(&str, &[&str], &str)] = &[
+    ("duckduckgo.com", &["/", "/html", "/html/", "/lite", "/lite/"], "q"),
+    ("html.duckduckgo.com", &["/html", "/html/"], "q"),
+    ("lite.duckduckgo.com", &["/lite", "/lite/"], "q"),
+    ("google.", &["/search"], "q"),
+    ("bing.com", &["/search"], "q"),
+    ("search.yahoo.com", &["/search"], "p"),
+    ("ecosia.org", &["/search"], "q"),
+    ("search.brave.com", &["/search"], "q"),
+    ("startpage.com", &["/do/search", "/sp/search"], "query"),
+    ("qwant.com", &["/"], "q"),
+    ("yandex.", &["/search", "/search/"], "text"),
+    ("baidu.com", &["/s"], "wd"),
+];
+
+/// Returns the decoded search query when `url` is a known engine's result page.
+pub(crate) fn extract_search_query(url: &str) -> Option<String> {
+    let u = Url::parse(url).ok()?;
+    if !matches!(u.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = u.host_str()?.to_ascii_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+
+    let (_, _, param) = SEARCH_ENGINES.iter().find(|(engine, paths, _)| {
+        let host_matches = match engine.strip_suffix('.') {
+            // `google.com`, `google.co.uk`, `google.com.br`, not `google.example.com`
+            Some(name) => host
+                .strip_prefix(name)
+                .and_then(|rest| rest.strip_prefix('.'))
+                .is_some_and(is_country_suffix),
+            None => host == *engine,
+        };
+        host_matches && paths.contains(&u.path())
+    })?;
+
+    u.query_pairs()
+        .find(|(k, _)| k == param)
+        .map(|(_, v)| v.trim().to_string())
+        .filter(|q| !q.is_empty())
+}
+
+/// `com`, `de`, `co.uk`, `com.br`: the suffixes engines use for country sites.
+fn is_country_suffix(suffix: &str) -> bool {
+    let labels: Vec<&str> = suffix.split('.').collect();
+    match labels.as_slice() {
+        [tld] => !tld.is_empty(),
+        [second, tld] => matches!(*second, "co" | "com") && !tld.is_empty(),
+        _ => false,
+    }
+}
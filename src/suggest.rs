@@ -0,0 +1,94 @@
+//! Completion suggestions
+//!
+//! Embedders register [`SuggestionSource`]s (bookmarks, history) on a
+//! `Classifier`; `Classifier::predict` returns their candidates for the typed
+//! prefix next to the decision. Ranking happens here so the omnibox orders
+//! suggestions the same way on every platform.
+
+use std::cmp::Reverse;
+
+use serde::{Deserialize, Serialize};
+
+/// Supplies completion candidates for a typed prefix.
+pub trait SuggestionSource: Send + Sync + 'static {
+    /// Candidates for `prefix` (trimmed, non-empty). Sources may match loosely;
+    /// candidates are re-ranked and at most `limit` of all sources are kept.
+    fn suggestions(&self, prefix: &str, limit: usize) -> Vec<Suggestion>;
+}
+
+/// A completion candidate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub kind: SuggestionKind,
+    /// Past visits, used to order otherwise equal candidates
+    #[serde(default)]
+    pub visit_count: u32,
+}
+
+/// Where a [`Suggestion`] comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SuggestionKind {
+    Bookmark,
+    History,
+}
+
+/// How well a candidate matches the prefix, best first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Match {
+    /// `git` → `github.com`, ignoring scheme and `www.`
+    Host,
+    /// `github.com/rust` → `github.com/rust-lang/rust`
+    Url,
+    /// A word of the title starts with the prefix
+    Title,
+    /// Whatever the source matched on
+    Other,
+}
+
+/// Orders `candidates` for `prefix`: match quality, then bookmarks before
+/// history, then visit count, then shorter URLs. Duplicate URLs and `exclude`
+/// (the decision's own URL) are dropped.
+pub(crate) fn rank(prefix: &str, candidates: Vec<Suggestion>, exclude: Option<&str>, limit: usize) -> Vec<Suggestion> {
+    let prefix = prefix.to_lowercase();
+    let mut ranked: Vec<(Match, Suggestion)> = Vec::new();
+    for suggestion in candidates {
+        if exclude == Some(suggestion.url.as_str()) {
+            continue;
+        }
+        let m = match_quality(&prefix, &suggestion);
+        match ranked.iter_mut().find(|(_, s)| s.url == suggestion.url) {
+            // duplicates keep their best-ranked copy
+            Some(existing) if rank_key(m, &suggestion) < rank_key(existing.0, &existing.1) => *existing = (m, suggestion),
+            Some(_) => {}
+            None => ranked.push((m, suggestion)),
+        }
+    }
+    ranked.sort_by_key(|(m, s)| rank_key(*m, s));
+    ranked.into_iter().take(limit).map(|(_, s)| s).collect()
+}
+
+fn rank_key(m: Match, s: &Suggestion) -> (Match, bool, Reverse<u32>, usize) {
+    (m, s.kind == SuggestionKind::History, Reverse(s.visit_count), s.url.len())
+}
+
+fn match_quality(prefix: &str, suggestion: &Suggestion) -> Match {
+    let url = suggestion.url.to_lowercase();
+    let without_scheme = url.split_once("://").map_or(url.as_str(), |(_, rest)| rest);
+    let without_www = without_scheme.strip_prefix("www.").unwrap_or(without_scheme);
+    let host_len = without_www.find(['/', '?', '#']).unwrap_or(without_www.len());
+
+    if without_www.starts_with(prefix) || without_scheme.starts_with(prefix) || url.starts_with(prefix) {
+        if prefix.len() <= host_len {
+            return Match::Host;
+        }
+        return Match::Url;
+    }
+    let title = suggestion.title.as_deref().unwrap_or_default().to_lowercase();
+    if title.split(|c: char| !c.is_alphanumeric()).any(|word| word.starts_with(prefix)) || title.starts_with(prefix) {
+        return Match::Title;
+    }
+    Match::Other
+}
@@ -0,0 +1,58 @@
+//! Classification telemetry
+//!
+//! A [`TelemetrySink`] sees one event per classification with categorical data
+//! only (decision kind, reason code, latency), enough to measure navigate vs.
+//! search ratios and performance. The input and URLs are never passed on.
+
+use std::ffi::{c_void, CString};
+use std::os::raw::c_char;
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
+
+use crate::{DecisionKind, ReasonCode};
+
+/// Receives an event for every classification.
+pub trait TelemetrySink: Send + Sync + 'static {
+    fn record(&self, kind: DecisionKind, reason: Option<ReasonCode>, latency_micros: u64);
+}
+
+/// C callback: `reason` is the `ReasonCode` name (`"Calculation"`, ...) or NULL,
+/// valid for the duration of the call; `user_data` is passed through.
+pub type TelemetryCallback =
+    unsafe extern "C" fn(kind: DecisionKind, reason: *const c_char, latency_micros: u64, user_data: *mut c_void);
+
+pub(crate) struct CallbackTelemetrySink {
+    callback: TelemetryCallback,
+    user_data: *mut c_void,
+}
+
+// The registering side promises the callback and `user_data` may be used from any thread.
+unsafe impl Send for CallbackTelemetrySink {}
+unsafe impl Sync for CallbackTelemetrySink {}
+
+impl CallbackTelemetrySink {
+    pub(crate) fn new(callback: TelemetryCallback, user_data: *mut c_void) -> Self {
+        Self { callback, user_data }
+    }
+}
+
+impl TelemetrySink for CallbackTelemetrySink {
+    fn record(&self, kind: DecisionKind, reason: Option<ReasonCode>, latency_micros: u64) {
+        let reason = reason
+            .and_then(|reason| serde_json::to_value(reason).ok())
+            .and_then(|name| CString::new(name.as_str()?).ok());
+        let reason_ptr = reason.as_ref().map_or(std::ptr::null(), |name| name.as_ptr());
+        unsafe { (self.callback)(kind, reason_ptr, latency_micros, self.user_data) }
+    }
+}
+
+static ACTIVE_SINK: Lazy<RwLock<Option<Arc<dyn TelemetrySink>>>> = Lazy::new(|| RwLock::new(None));
+
+pub(crate) fn install(sink: Option<Arc<dyn TelemetrySink>>) {
+    *ACTIVE_SINK.write().unwrap_or_else(|e| e.into_inner()) = sink;
+}
+
+pub(crate) fn active_sink() -> Option<Arc<dyn TelemetrySink>> {
+    ACTIVE_SINK.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
@@ -0,0 +1,52 @@
+//! UTS-46 processing modes
+//!
+//! `idna` and `url` only implement nontransitional processing, as current
+//! Chromium, Firefox and the WHATWG URL standard do. Transitional processing
+//! (older WebKit/Chromium) additionally maps the four deviation characters
+//! before encoding, so `faß.de` becomes `fass.de` rather than `xn--fa-hia.de`.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::is_valid_scheme;
+
+/// Applies the transitional deviation mappings to the host part of `input`,
+/// leaving scheme, userinfo, port, path, query and fragment untouched.
+pub(crate) fn map_transitional_host(input: &str) -> Cow<'_, str> {
+    let Range { start: host_start, end: host_end } = host_range(input);
+    let host = &input[host_start..host_end];
+    if !host.chars().any(is_deviation) {
+        return Cow::Borrowed(input);
+    }
+    let mut mapped = String::with_capacity(input.len());
+    mapped.push_str(&input[..host_start]);
+    for c in host.chars() {
+        match c {
+            'ß' | 'ẞ' => mapped.push_str("ss"),
+            'ς' => mapped.push('σ'),
+            '\u{200C}' | '\u{200D}' => {}
+            _ => mapped.push(c),
+        }
+    }
+    mapped.push_str(&input[host_end..]);
+    Cow::Owned(mapped)
+}
+
+/// Where the host (with any port) of URL-shaped `input` is, as typed.
+pub(crate) fn host_range(input: &str) -> Range<usize> {
+    // a `://` later in the path or query (`?r=https://x.com`) isn't the scheme's
+    let scheme_end = input.find("://").filter(|&i| is_valid_scheme(&input[..i]));
+    let authority_start = match scheme_end {
+        Some(i) => i + 3,
+        None if input.starts_with("//") => 2,
+        None => 0,
+    };
+    let authority_len = input[authority_start..].find(['/', '?', '#']).unwrap_or(input.len() - authority_start);
+    let authority = &input[authority_start..authority_start + authority_len];
+    authority_start + authority.rfind('@').map_or(0, |i| i + 1)..authority_start + authority_len
+}
+
+/// `ß`, `ẞ`, `ς`, ZWNJ and ZWJ: the characters the two modes treat differently.
+fn is_deviation(c: char) -> bool {
+    matches!(c, 'ß' | 'ẞ' | 'ς' | '\u{200C}' | '\u{200D}')
+}
@@ -0,0 +1,121 @@
+//! Policy JSON diagnostics
+//!
+//! The classify entry points fall back to the default policy when the policy
+//! JSON doesn't parse, which hides a malformed remote config. [`validate_policy_json`]
+//! reports what is wrong instead, with the path of the field and where in the
+//! JSON it is, so settings screens can point at it.
+
+use serde::{Deserialize, Serialize};
+
+use crate::Policy;
+
+/// One problem with a policy's JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyDiagnostic {
+    /// Dotted path of the field (`normalization.tracking_params`, `allowed_schemes[2]`),
+    /// or `.` for the document itself
+    pub path: String,
+    pub kind: PolicyDiagnosticKind,
+    /// Human-readable detail, in English
+    pub message: String,
+    /// Byte offset into the JSON just past where the problem was found (the
+    /// end of the offending value), if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+}
+
+/// What is wrong with a policy field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PolicyDiagnosticKind {
+    /// Not JSON
+    Syntax,
+    /// The JSON ends early
+    Eof,
+    /// A field without a default is absent
+    MissingField,
+    /// A field appears twice
+    DuplicateField,
+    /// A value of the wrong JSON type (a string where a bool belongs)
+    InvalidType,
+    /// An enum value this version doesn't know
+    UnknownVariant,
+    /// A value of the right type that isn't allowed
+    InvalidValue,
+    /// A `keywords` template that would be ignored when classifying
+    InvalidKeyword,
+    /// A field this version doesn't know, which is ignored; not an error, but
+    /// often a typo or a field from a newer version
+    UnknownField,
+}
+
+impl PolicyDiagnosticKind {
+    /// Whether a policy with this problem is rejected (the default policy is
+    /// used instead) rather than applied without the field.
+    pub fn is_error(self) -> bool {
+        !matches!(self, PolicyDiagnosticKind::UnknownField | PolicyDiagnosticKind::InvalidKeyword)
+    }
+}
+
+/// Checks `json` as a [`Policy`], returning every unknown field and invalid
+/// keyword, and the first error that keeps it from parsing. Empty when the
+/// policy is valid.
+pub fn validate_policy_json(json: &str) -> Vec<PolicyDiagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut unknown = Vec::new();
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let mut record_unknown = |path: serde_ignored::Path<'_>| unknown.push(path.to_string());
+    let ignoring = serde_ignored::Deserializer::new(&mut deserializer, &mut record_unknown);
+    let parsed = serde_path_to_error::deserialize::<_, Policy>(ignoring)
+        .map_err(|e| (e.path().to_string(), e.into_inner()))
+        // nothing but whitespace may follow the object
+        .and_then(|policy| deserializer.end().map(|()| policy).map_err(|e| (".".to_string(), e)));
+    diagnostics.extend(unknown.into_iter().map(|path| PolicyDiagnostic {
+        message: format!("unknown field `{}`, ignored", path.rsplit('.').next().unwrap_or_default()),
+        path,
+        kind: PolicyDiagnosticKind::UnknownField,
+        offset: None,
+    }));
+    match parsed {
+        Ok(policy) => {
+            diagnostics.extend(policy.keywords.iter().filter_map(|(keyword, template)| {
+                let message = crate::keyword::validate(keyword, template).err()?;
+                Some(PolicyDiagnostic { path: format!("keywords.{keyword}"), kind: PolicyDiagnosticKind::InvalidKeyword, message, offset: None })
+            }));
+        }
+        Err((path, inner)) => {
+            let kind = match inner.classify() {
+                serde_json::error::Category::Syntax | serde_json::error::Category::Io => PolicyDiagnosticKind::Syntax,
+                serde_json::error::Category::Eof => PolicyDiagnosticKind::Eof,
+                serde_json::error::Category::Data => data_error_kind(&inner.to_string()),
+            };
+            // serde_json's message ends with " at line L column C"
+            let message = inner.to_string();
+            let message = message.split(" at line ").next().unwrap_or(&message).to_string();
+            diagnostics.push(PolicyDiagnostic { path, kind, message, offset: offset(json, inner.line(), inner.column()) });
+        }
+    }
+    diagnostics
+}
+
+/// Serde's messages for data errors start with what went wrong.
+fn data_error_kind(message: &str) -> PolicyDiagnosticKind {
+    [
+        ("missing field", PolicyDiagnosticKind::MissingField),
+        ("duplicate field", PolicyDiagnosticKind::DuplicateField),
+        ("invalid type", PolicyDiagnosticKind::InvalidType),
+        ("unknown variant", PolicyDiagnosticKind::UnknownVariant),
+    ]
+    .into_iter()
+    .find_map(|(prefix, kind)| message.starts_with(prefix).then_some(kind))
+    .unwrap_or(PolicyDiagnosticKind::InvalidValue)
+}
+
+/// The byte offset past serde_json's 1-based `line` and byte `column`, which
+/// point at the last byte read.
+fn offset(json: &str, line: usize, column: usize) -> Option<usize> {
+    if line == 0 {
+        return None;
+    }
+    let line_start: usize = json.split_inclusive('\n').take(line - 1).map(str::len).sum();
+    Some((line_start + column).min(json.len()))
+}
@@ -0,0 +1,112 @@
+//! Versioned JSON wire format
+//!
+//! `ddg_up_classify_json` and friends return a bare decision in the v1 shape,
+//! which clients pattern-match exactly. v1 is frozen: fields, warnings, reason
+//! codes and transforms added later are dropped from it, and decision kinds it
+//! doesn't know fall back to searching the input. The v2 entry points wrap the
+//! full decision in `{"v":2,"decision":…}` and evolve additively.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Decision;
+
+/// Version of the [`DecisionEnvelope`] this build writes.
+pub const WIRE_VERSION: u32 = 2;
+
+/// Fields each v1 decision kind carries.
+const V1_FIELDS: &[(&str, &[&str])] = &[
+    (
+        "Navigate",
+        &["url", "search_alternative", "warnings", "display_host", "transforms", "idna_processing", "file_kind"],
+    ),
+    ("Search", &["query", "unknown_scheme_navigation", "reason", "hint"]),
+    ("Blocked", &["url", "threat", "reason"]),
+];
+
+const V1_REASON_CODES: &[&str] = &[
+    "Calculation", "Conversion", "EmailAddress", "PossibleSpoof", "MixedScript", "Credentials", "Reputation",
+    "DataUrlTooLong", "DataUrlMimeType", "DataUrlScript", "ShellScheme", "ExecutableFile",
+];
+
+const V1_WARNINGS: &[&str] = &["PossibleSpoof", "MixedScript", "CredentialsStripped", "Reputation", "ExecutableFile"];
+
+const V1_TRANSFORMS: &[&str] = &["HstsUpgrade", "Bang", "Keyword"];
+
+/// A decision tagged with the wire version it was written in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecisionEnvelope {
+    pub v: u32,
+    pub decision: Decision,
+}
+
+impl DecisionEnvelope {
+    pub fn new(decision: Decision) -> Self {
+        Self { v: WIRE_VERSION, decision }
+    }
+
+    /// Reads an envelope, or a bare v1 decision (as `v: 1`).
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let value: Value = serde_json::from_str(json)?;
+        if value.get("v").is_some() {
+            serde_json::from_value(value)
+        } else {
+            serde_json::from_value(value).map(|decision| Self { v: 1, decision })
+        }
+    }
+}
+
+/// `decision` in the frozen v1 shape; `input` is searched for when the kind is
+/// newer than v1.
+pub fn decision_v1_json(decision: &Decision, input: &str) -> String {
+    serde_json::to_value(decision)
+        .ok()
+        .and_then(to_v1)
+        .unwrap_or_else(|| serde_json::json!({ "Search": { "query": input } }).to_string())
+}
+
+/// Strips a serialized decision down to v1 JSON, `None` for kinds v1 doesn't have.
+pub(crate) fn to_v1(value: Value) -> Option<String> {
+    let Value::Object(decision) = value else {
+        return None;
+    };
+    let (kind, fields) = decision.into_iter().next()?;
+    let (_, v1_fields) = V1_FIELDS.iter().find(|(name, _)| *name == kind)?;
+    let Value::Object(fields) = fields else {
+        return None;
+    };
+    let mut v1 = serde_json::Map::new();
+    for (name, value) in fields {
+        if !v1_fields.contains(&name.as_str()) {
+            continue;
+        }
+        let value = match name.as_str() {
+            "reason" if !is_v1_name(&value, V1_REASON_CODES) => continue,
+            "warnings" => retain_v1(value, V1_WARNINGS),
+            "transforms" => retain_v1(value, V1_TRANSFORMS),
+            _ => value,
+        };
+        // v1 omits empty lists, so one left holding only newer entries goes too
+        if value.as_array().is_some_and(Vec::is_empty) {
+            continue;
+        }
+        v1.insert(name, value);
+    }
+    Some(serde_json::json!({ kind: v1 }).to_string())
+}
+
+/// Whether `value` is a unit variant in `names`, or a struct variant keyed by one.
+fn is_v1_name(value: &Value, names: &[&str]) -> bool {
+    match value {
+        Value::String(name) => names.contains(&name.as_str()),
+        Value::Object(map) => map.keys().all(|name| names.contains(&name.as_str())),
+        _ => false,
+    }
+}
+
+fn retain_v1(value: Value, names: &[&str]) -> Value {
+    match value {
+        Value::Array(items) => Value::Array(items.into_iter().filter(|item| is_v1_name(item, names)).collect()),
+        other => other,
+    }
+}
@@ -0,0 +1,56 @@
+//! Background classification for the FFI
+//!
+//! [`ddg_up_classify_async`](crate::ddg_up_classify_async) hands inputs to
+//! one worker thread, started on first use, and reports each decision through
+//! a completion callback. Callers on a UI thread never block on
+//! classification, including lookups that may need I/O once a suffix DB or
+//! probe does. Jobs run one at a time, in the order they were queued.
+
+use std::ffi::c_void;
+use std::os::raw::c_char;
+use std::sync::mpsc::{self, Sender};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+/// C callback: `decision_json` is the v1 decision JSON, valid for the
+/// duration of the call; `user_data` is passed through.
+pub type ClassifyCallback = unsafe extern "C" fn(decision_json: *const c_char, user_data: *mut c_void);
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// `user_data` of a queued job.
+pub(crate) struct UserData(pub(crate) *mut c_void);
+
+// The caller promises `user_data` may be used from the worker thread.
+unsafe impl Send for UserData {}
+
+static WORKER: Lazy<Mutex<Option<Sender<Job>>>> = Lazy::new(|| Mutex::new(spawn()));
+
+fn spawn() -> Option<Sender<Job>> {
+    let (tx, rx) = mpsc::channel::<Job>();
+    let spawned = std::thread::Builder::new().name("url_predictor-classify".into()).spawn(move || {
+        for job in rx {
+            // a panicking job must not take later jobs down with it
+            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(job));
+        }
+    });
+    spawned.ok().map(|_| tx)
+}
+
+/// Runs `job` on the worker, or on the calling thread if no worker thread
+/// can be started.
+pub(crate) fn submit(job: Job) {
+    let mut worker = WORKER.lock().unwrap_or_else(|e| e.into_inner());
+    if worker.is_none() {
+        *worker = spawn();
+    }
+    let Some(tx) = worker.as_ref() else {
+        drop(worker);
+        return job();
+    };
+    if let Err(mpsc::SendError(job)) = tx.send(job) {
+        drop(worker);
+        job();
+    }
+}
@@ -0,0 +1,66 @@
+// tests/golden.rs
+//
+// Snapshots the decision for every input of tests/golden/corpus.txt under the
+// platform presets, so a heuristic change shows up as a reviewable diff of
+// every decision it flips. Review with `cargo insta review`, or accept with
+// `INSTA_UPDATE=always cargo test --test golden`.
+//
+// The demo PSL and the default feature set only: `real-psl`, `hsts-preload`,
+// `bangs` and `intents` change decisions by design.
+#![cfg(not(any(feature = "real-psl", feature = "hsts-preload", feature = "bangs", feature = "intents")))]
+
+use url_predictor::{classify, Decision, PolicyPreset};
+
+const CORPUS: &str = include_str!("golden/corpus.txt");
+
+/// One line per input: the input, then the decision as JSON.
+fn snapshot(preset: PolicyPreset) -> String {
+    let policy = preset.policy();
+    CORPUS
+        .lines()
+        .map(|input| format!("{input:?} => {}\n", serde_json::to_string(&classify(input, &policy)).unwrap()))
+        .collect()
+}
+
+#[test]
+fn golden_default() {
+    insta::assert_snapshot!(snapshot(PolicyPreset::Default));
+}
+
+#[test]
+fn golden_windows() {
+    insta::assert_snapshot!(snapshot(PolicyPreset::Windows));
+}
+
+#[test]
+fn golden_macos() {
+    insta::assert_snapshot!(snapshot(PolicyPreset::MacOs));
+}
+
+#[test]
+fn golden_android() {
+    insta::assert_snapshot!(snapshot(PolicyPreset::Android));
+}
+
+#[test]
+fn golden_ios() {
+    insta::assert_snapshot!(snapshot(PolicyPreset::Ios));
+}
+
+/// Clients round-trip decisions through the predictor, so classifying a
+/// navigated URL must navigate to exactly that URL.
+#[test]
+fn golden_navigations_round_trip() {
+    for preset in [PolicyPreset::Default, PolicyPreset::Windows, PolicyPreset::MacOs, PolicyPreset::Android, PolicyPreset::Ios] {
+        let policy = preset.policy();
+        for input in CORPUS.lines() {
+            if let Decision::Navigate { url, .. } = classify(input, &policy) {
+                let again = classify(&url, &policy);
+                assert!(
+                    matches!(&again, Decision::Navigate { url: again, .. } if *again == url),
+                    "{preset:?}: {input:?} navigates to {url:?}, which decides {again:?}"
+                );
+            }
+        }
+    }
+}